@@ -0,0 +1,107 @@
+// Module to bring young players through to the senior squad
+
+pub mod academy {
+    use crate::{
+        career::career::age_player,
+        player::player::{get_position, Player, Position},
+        random_engine::{
+            rnd_name::{pick_nationality, NameGenerator, Nationality},
+            rng_eng::{rand_index, roll, Attributes, RollType, ATTRIBUTE_TYPES},
+        },
+        team::team::{Team, STARTERS},
+    };
+
+    // Prospects taken on each year
+    const ACADEMY_INTAKE: usize = 4;
+    // Ages of a new intake
+    const MIN_INTAKE_AGE: u8 = 16;
+    const MAX_INTAKE_AGE: u8 = 17;
+    // Prospects can join the senior squad from this age, and are released after
+    const PROMOTION_AGE: u8 = 18;
+    const RELEASE_AGE: u8 = 21;
+    // Prospects start well below an established player
+    const PROSPECT_ATTRIBUTE_MIN: i8 = -5;
+    const PROSPECT_ATTRIBUTE_MAX: i8 = -1;
+
+    // Academy
+    // Young players developing until they are ready for the senior squad
+    pub struct Academy {
+        pub prospects: Vec<Player>,
+    }
+
+    impl Academy {
+        pub fn new() -> Academy {
+            Academy {
+                prospects: Vec::new(),
+            }
+        }
+
+        // New young player in a random position
+        // Potential is half a d20, so a few prospects can become the best in the game
+        pub fn prospect(&self, nationality: Nationality, names: &mut NameGenerator) -> Player {
+            let mut player = Player::new();
+            player.nationality = nationality;
+            player.name = names.unique_name(nationality);
+            player.age =
+                MIN_INTAKE_AGE + rand_index((MAX_INTAKE_AGE - MIN_INTAKE_AGE + 1).into()) as u8;
+            player.position = vec![get_position(rand_index(STARTERS) as u8 + 1)];
+            player.set_build();
+            player.attributes = Attributes::random(PROSPECT_ATTRIBUTE_MIN, PROSPECT_ATTRIBUTE_MAX);
+            player.potential = (roll(0, RollType::Flat).0 / 2) as i8;
+            player.is_selected = false;
+            player
+        }
+
+        // Take on this year's intake, drawn from the club's nationality mix
+        pub fn recruit(&mut self, mix: &[(Nationality, u32)], names: &mut NameGenerator) {
+            for _ in 0..ACADEMY_INTAKE {
+                let prospect = self.prospect(pick_nationality(mix), names);
+                self.prospects.push(prospect);
+            }
+        }
+
+        // Age every prospect by a year and release those too old to stay
+        pub fn develop(&mut self) -> Vec<Player> {
+            for prospect in self.prospects.iter_mut() {
+                age_player(prospect);
+            }
+            let (released, kept) = self.prospects.drain(..).partition(|x| x.age > RELEASE_AGE);
+            self.prospects = kept;
+            released
+        }
+
+        // Prospect ready to step up, most promising first
+        pub fn best_prospect(&self, positions: &[Position]) -> Option<usize> {
+            (0..self.prospects.len())
+                .filter(|&i| {
+                    self.prospects[i].age >= PROMOTION_AGE
+                        && positions.contains(&self.prospects[i].position[0])
+                })
+                .max_by_key(|&i| promise(&self.prospects[i]))
+        }
+
+        // Replace a squad player with the best prospect in their position
+        // Returns false when no prospect is ready
+        pub fn promote(&mut self, team: &mut Team, slot: usize) -> bool {
+            let outgoing = &team.players[slot];
+            let Some(best) = self.best_prospect(&outgoing.position) else {
+                return false;
+            };
+
+            let mut player = self.prospects.remove(best);
+            player.selected_position = outgoing.selected_position;
+            player.is_selected = outgoing.is_selected;
+            team.players[slot] = player;
+            true
+        }
+    }
+
+    // Current ability and room to grow
+    fn promise(player: &Player) -> i32 {
+        let ability = ATTRIBUTE_TYPES
+            .iter()
+            .map(|&x| player.attributes.get(x) as i32)
+            .sum::<i32>();
+        ability + ATTRIBUTE_TYPES.len() as i32 * player.potential as i32
+    }
+}
@@ -0,0 +1,267 @@
+// Module to run a club over many seasons
+
+pub mod career {
+    use crate::{
+        academy::academy::Academy,
+        competition::competition::{Competition, Standing},
+        finance::finance::{close_season, Accounts},
+        player::player::{Player, Position, BACK_ROW, FRONT_ROW, SECOND_ROW},
+        random_engine::{
+            rnd_name::NameGenerator,
+            rng_eng::{rand_chance, rand_index, AttributeTypes, ATTRIBUTE_TYPES},
+        },
+        team::team::Team,
+        transfer::transfer::{Transfer, TransferMarket},
+    };
+
+    // Youngsters join the senior squad at these ages
+    const MIN_ROOKIE_AGE: u8 = 18;
+    const MAX_ROOKIE_AGE: u8 = 21;
+    // Rookies start below an established player
+    const ROOKIE_ATTRIBUTE: i8 = -2;
+    // Chance of an attribute changing each year while developing or declining
+    const DEVELOP_CHANCE: f32 = 0.4;
+    const DECLINE_CHANCE: f32 = 0.5;
+    // Extra chance of developing for every game played in the season
+    const GAME_TIME_CHANCE: f32 = 0.02;
+    // Chance of retiring at the typical age, rising each year after
+    const RETIRE_CHANCE: f32 = 0.4;
+    const RETIRE_CHANCE_PER_YEAR: f32 = 0.2;
+
+    // Physical attributes peak early, mental attributes keep developing
+    const PHYSICAL: [AttributeTypes; 3] = [
+        AttributeTypes::Strength,
+        AttributeTypes::Constitution,
+        AttributeTypes::Dexterity,
+    ];
+
+    // Ages that shape a player's career
+    #[derive(Clone, Copy)]
+    pub struct AgeCurve {
+        pub peak: u8,    // Physical development stops
+        pub decline: u8, // Physical decline starts, mental development stops
+        pub retire: u8,  // Typical retirement age
+    }
+
+    // Age curve for a position, tight forwards last longest and back three shortest
+    pub fn age_curve(pos: Position) -> AgeCurve {
+        let (peak, decline, retire) = match pos {
+            _ if FRONT_ROW.contains(&pos) => (27, 32, 35),
+            _ if SECOND_ROW.contains(&pos) => (26, 31, 34),
+            _ if BACK_ROW.contains(&pos) => (25, 30, 33),
+            Position::ScrumHalf | Position::FlyHalf => (26, 31, 34),
+            Position::InsideCentre | Position::OutsideCentre => (25, 30, 33),
+            _ => (24, 29, 32),
+        };
+        AgeCurve {
+            peak,
+            decline,
+            retire,
+        }
+    }
+
+    // Main position of a player
+    fn main_position(player: &Player) -> Position {
+        player.position.first().copied().unwrap_or(Position::Sub)
+    }
+
+    // Age a player by a year and develop or decline their attributes
+    // Game time helps a player develop, but never past their potential
+    pub fn age_player(player: &mut Player) {
+        let curve = age_curve(main_position(player));
+        let chance = DEVELOP_CHANCE + player.games as f32 * GAME_TIME_CHANCE;
+        for attr in ATTRIBUTE_TYPES {
+            let develop = match player.attributes.get(attr) < player.potential {
+                true => rand_chance(chance) as i8,
+                false => 0,
+            };
+            let change = match (PHYSICAL.contains(&attr), player.age) {
+                (true, age) if age < curve.peak => develop,
+                (false, age) if age < curve.decline => develop,
+                // Decline speeds up for every year past the turn
+                (true, age) if age >= curve.decline => {
+                    -(1 + (age - curve.decline) as i8 / 2) * decline()
+                }
+                _ => 0,
+            };
+            player.attributes.add(attr, change);
+        }
+        player.age += 1;
+        player.games = 0;
+    }
+
+    fn decline() -> i8 {
+        rand_chance(DECLINE_CHANCE) as i8
+    }
+
+    // Whether a player hangs up their boots this summer
+    pub fn retires(player: &Player) -> bool {
+        let curve = age_curve(main_position(player));
+        match player.age {
+            age if age < curve.retire => false,
+            age => {
+                rand_chance(RETIRE_CHANCE + (age - curve.retire) as f32 * RETIRE_CHANCE_PER_YEAR)
+            }
+        }
+    }
+
+    // Young player to take over a squad place when the academy has nobody ready
+    pub fn rookie(like: &Player, names: &mut NameGenerator) -> Player {
+        let mut player = Player::new();
+        player.nationality = like.nationality;
+        player.name = names.unique_name(like.nationality);
+        player.age =
+            MIN_ROOKIE_AGE + rand_index((MAX_ROOKIE_AGE - MIN_ROOKIE_AGE + 1).into()) as u8;
+        player.position = like.position.clone();
+        player.set_build();
+        player.selected_position = like.selected_position;
+        player.is_selected = like.is_selected;
+        for attr in ATTRIBUTE_TYPES {
+            player.attributes.set(attr, ROOKIE_ATTRIBUTE);
+        }
+        player
+    }
+
+    // Player who left the game at the end of a season
+    pub struct Retirement {
+        pub team: usize,
+        pub name: String,
+        pub age: u8,
+    }
+
+    // Summary of a finished season
+    pub struct Season {
+        pub year: u32,
+        pub table: Vec<Standing>,
+        pub retirements: Vec<Retirement>,
+        pub promotions: Vec<(usize, String)>, // Team and name of each academy graduate
+        pub transfers: Vec<Transfer>,
+        pub accounts: Vec<Accounts>, // Books of each team
+    }
+
+    impl Season {
+        // League winner
+        pub fn champion(&self) -> Option<usize> {
+            self.table.first().map(|x| x.team)
+        }
+
+        // Final league position of a team, starting from one
+        pub fn position(&self, team: usize) -> Option<usize> {
+            self.table
+                .iter()
+                .position(|x| x.team == team)
+                .map(|x| x + 1)
+        }
+    }
+
+    // Career
+    // A club and its league over many seasons
+    pub struct Career {
+        pub club: usize, // Index of the club in the league
+        pub league: Competition,
+        pub year: u32,
+        pub seasons: Vec<Season>,
+        pub academies: Vec<Academy>, // One for each team in the league
+        pub market: TransferMarket,
+        pub names: NameGenerator, // Keeps names unique across the league
+    }
+
+    impl Career {
+        // Names already in the league are kept, apart from any repeats
+        pub fn new(mut league: Competition, club: usize, year: u32) -> Career {
+            let mut names = NameGenerator::new();
+            for team in league.teams.iter_mut() {
+                team.claim_names(&mut names);
+            }
+            Career {
+                club,
+                academies: league.teams.iter().map(|_| Academy::new()).collect(),
+                league,
+                year,
+                seasons: Vec::new(),
+                market: TransferMarket::new(),
+                names,
+            }
+        }
+
+        pub fn club(&self) -> &Team {
+            &self.league.teams[self.club]
+        }
+
+        // Play a league season, then age every squad and academy
+        // Retired players are replaced from the academy, or by a rookie
+        // Clubs are paid their sponsorship and wages before the transfer window
+        pub fn play_season(&mut self) -> &Season {
+            self.league.new_season();
+            self.league.play_all();
+            let table = self.league.table();
+            let accounts = (0..self.league.teams.len())
+                .map(|i| {
+                    let position = table.iter().position(|x| x.team == i).unwrap() + 1;
+                    close_season(&mut self.league.teams[i], position)
+                })
+                .collect();
+
+            let mut retirements = Vec::new();
+            let mut promotions = Vec::new();
+            let names = &mut self.names;
+            for (i, team) in self.league.teams.iter_mut().enumerate() {
+                let academy = &mut self.academies[i];
+                // Prospects let go by the academy free up their names
+                for prospect in academy.develop() {
+                    names.release(&prospect.name);
+                }
+                for slot in 0..team.players.len() {
+                    age_player(&mut team.players[slot]);
+                    if retires(&team.players[slot]) {
+                        names.release(&team.players[slot].name);
+                        retirements.push(Retirement {
+                            team: i,
+                            name: team.players[slot].name.clone(),
+                            age: team.players[slot].age,
+                        });
+                        match academy.promote(team, slot) {
+                            true => promotions.push((i, team.players[slot].name.clone())),
+                            false => team.players[slot] = rookie(&team.players[slot], names),
+                        }
+                    }
+                }
+                academy.recruit(&team.nationalities, names);
+            }
+
+            // Free agents age too, and some give up waiting for a club
+            for player in self.market.free_agents.iter_mut() {
+                age_player(player);
+            }
+            let (retired, waiting): (Vec<Player>, Vec<Player>) =
+                self.market.free_agents.drain(..).partition(retires);
+            for player in retired {
+                self.names.release(&player.name);
+            }
+            self.market.free_agents = waiting;
+            let transfers = self.market.window(&mut self.league.teams, &mut self.names);
+            // New faces in the squad, the leadership group is picked again
+            for team in self.league.teams.iter_mut() {
+                team.pick_leaders();
+            }
+
+            self.seasons.push(Season {
+                year: self.year,
+                table,
+                retirements,
+                promotions,
+                transfers,
+                accounts,
+            });
+            self.year += 1;
+            self.seasons.last().unwrap()
+        }
+
+        // Play a number of seasons in a row
+        pub fn play_seasons(&mut self, seasons: u32) {
+            for _ in 0..seasons {
+                self.play_season();
+            }
+        }
+    }
+}
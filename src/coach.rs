@@ -0,0 +1,260 @@
+// Module to handle the in-match decisions
+
+pub mod coach {
+    use crate::{pitch::pitch::PitchPosition, random_engine::rng_eng::RollResult};
+
+    // Penalty kicks easier than this are worth the points
+    const KICKABLE_DIFFICULTY: i32 = 16;
+    // Drop goals are only worth a go from close in
+    const DROP_GOAL_DIFFICULTY: i32 = 14;
+    // Kicks a captain should never turn down
+    const SITTER_DIFFICULTY: i32 = 12;
+    // Captain's Wisdom check that sees past the plan
+    const SHARP_READ: i32 = 18;
+    // Final stages of a match
+    const LATE_MINUTE: u32 = 70;
+    // Conservative teams kick from anywhere this close to their own line
+    const EXIT_ZONE: f32 = 35.;
+    // Replacements come on in two waves, front row first
+    const FRONT_ROW_REPLACEMENTS: usize = 3;
+    const ALL_REPLACEMENTS: usize = 8;
+
+    // Rough points expected from a line out in each area of the pitch
+    // Used by the data driven coach to weigh up kicking to touch
+    const LINE_OUT_POINTS_22: f32 = 2.5;
+    const LINE_OUT_POINTS_OPP_HALF: f32 = 1.2;
+    const LINE_OUT_POINTS_OWN_HALF: f32 = 0.4;
+    // Metres gained by a typical kick to touch
+    const TOUCH_KICK_GAIN: f32 = 20.;
+
+    // Options for the team awarded a penalty
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum PenaltyOption {
+        KickAtGoal,
+        KickToTouch, // Line out with the throw
+        TapAndGo,
+        Scrum,
+    }
+
+    // Options for the team in possession in open play
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum PhaseOption {
+        Run,
+        Kick, // Kick for territory
+        DropGoal,
+    }
+
+    // Snapshot of the match from the point of view of the deciding team
+    #[derive(Clone, Copy)]
+    pub struct MatchState {
+        pub minute: u32,
+        pub score_diff: i32, // Points for minus points against
+        pub pos: PitchPosition,
+        pub is_home: bool,
+    }
+
+    impl MatchState {
+        // Difficulty of a kick at goal from the current position
+        pub fn kick_difficulty(&self) -> i32 {
+            self.pos.goal_kick_difficutly(&self.is_home)
+        }
+
+        // Late in a match that can be won or drawn with a single score
+        pub fn late_and_close(&self) -> bool {
+            self.minute >= LATE_MINUTE && (-3..=0).contains(&self.score_diff)
+        }
+    }
+
+    // Captain's call on the penalty option from the coach, after a Wisdom check
+    // A sharp read takes an easy three or goes for the corner when only tries will do
+    // A critical failure talks the team out of the plan
+    pub fn captain_call(
+        option: PenaltyOption,
+        state: &MatchState,
+        read: (i32, RollResult),
+    ) -> PenaltyOption {
+        let chasing = state.minute >= LATE_MINUTE && state.score_diff < -7;
+        match (read, option) {
+            ((_, RollResult::CriticalFail), PenaltyOption::KickAtGoal) => {
+                PenaltyOption::KickToTouch
+            }
+            ((_, RollResult::CriticalFail), PenaltyOption::KickToTouch) => PenaltyOption::TapAndGo,
+            ((_, RollResult::CriticalFail), _) => option,
+            ((score, _), PenaltyOption::KickAtGoal) if score >= SHARP_READ && chasing => {
+                PenaltyOption::KickToTouch
+            }
+            ((score, _), _)
+                if score >= SHARP_READ
+                    && !chasing
+                    && state.kick_difficulty() <= SITTER_DIFFICULTY =>
+            {
+                PenaltyOption::KickAtGoal
+            }
+            _ => option,
+        }
+    }
+
+    // Decisions a team has to make during a match
+    pub trait DecisionPolicy {
+        // Play on under advantage or take the penalty now
+        fn play_advantage(&self, state: &MatchState) -> bool;
+
+        // What to do with a penalty
+        fn penalty_option(&self, state: &MatchState) -> PenaltyOption;
+
+        // Run, kick or go for the drop goal with ball in hand
+        fn phase_option(&self, state: &MatchState) -> PhaseOption;
+
+        // Replacements that should be on the pitch by now
+        fn replacements(&self, state: &MatchState) -> usize;
+    }
+
+    // Conservative
+    // Takes the points whenever they are on offer, otherwise kicks for territory
+    pub struct Conservative;
+
+    impl DecisionPolicy for Conservative {
+        fn play_advantage(&self, state: &MatchState) -> bool {
+            state.kick_difficulty() > KICKABLE_DIFFICULTY
+        }
+
+        fn penalty_option(&self, state: &MatchState) -> PenaltyOption {
+            if state.kick_difficulty() <= KICKABLE_DIFFICULTY {
+                PenaltyOption::KickAtGoal
+            } else {
+                PenaltyOption::KickToTouch
+            }
+        }
+
+        // Kick to exit our own end, play everywhere else
+        fn phase_option(&self, state: &MatchState) -> PhaseOption {
+            if state.late_and_close() && state.kick_difficulty() <= DROP_GOAL_DIFFICULTY {
+                PhaseOption::DropGoal
+            } else if state.pos.own_line_dist(&state.is_home) < EXIT_ZONE {
+                PhaseOption::Kick
+            } else {
+                PhaseOption::Run
+            }
+        }
+
+        fn replacements(&self, state: &MatchState) -> usize {
+            match state.minute {
+                0..=54 => 0,
+                55..=64 => FRONT_ROW_REPLACEMENTS,
+                _ => ALL_REPLACEMENTS,
+            }
+        }
+    }
+
+    // Aggressive
+    // Goes for tries, plays advantage and only kicks to get out of trouble
+    pub struct Aggressive;
+
+    impl DecisionPolicy for Aggressive {
+        fn play_advantage(&self, _state: &MatchState) -> bool {
+            true
+        }
+
+        // Line outs for the maul, tap and go on the line
+        // Still takes the three points to win it late on
+        fn penalty_option(&self, state: &MatchState) -> PenaltyOption {
+            if state.late_and_close() && state.kick_difficulty() <= KICKABLE_DIFFICULTY {
+                PenaltyOption::KickAtGoal
+            } else if state.pos.in_own_5m(&!state.is_home) {
+                PenaltyOption::TapAndGo
+            } else if state.pos.in_own_22(&!state.is_home) {
+                PenaltyOption::Scrum
+            } else {
+                PenaltyOption::KickToTouch
+            }
+        }
+
+        fn phase_option(&self, state: &MatchState) -> PhaseOption {
+            match state.pos.in_own_22(&state.is_home) {
+                true => PhaseOption::Kick,
+                false => PhaseOption::Run,
+            }
+        }
+
+        // Fresh legs early
+        fn replacements(&self, state: &MatchState) -> usize {
+            match state.minute {
+                0..=44 => 0,
+                45..=54 => FRONT_ROW_REPLACEMENTS,
+                _ => ALL_REPLACEMENTS,
+            }
+        }
+    }
+
+    // Data driven
+    // Weighs up the expected points of each option
+    pub struct DataDriven;
+
+    impl DataDriven {
+        // Chance of landing a kick of the given difficulty with a d20
+        fn kick_chance(difficulty: i32) -> f32 {
+            ((21 - difficulty) as f32 / 20.).clamp(0.05, 0.95)
+        }
+
+        // Expected points from a line out at the given position
+        fn line_out_points(pos: &PitchPosition, is_home: &bool) -> f32 {
+            if pos.in_own_22(&!is_home) {
+                LINE_OUT_POINTS_22
+            } else if !pos.in_own_half(is_home) {
+                LINE_OUT_POINTS_OPP_HALF
+            } else {
+                LINE_OUT_POINTS_OWN_HALF
+            }
+        }
+    }
+
+    impl DecisionPolicy for DataDriven {
+        // Only play on when the penalty is not worth more than a line out
+        fn play_advantage(&self, state: &MatchState) -> bool {
+            self.penalty_option(state) != PenaltyOption::KickAtGoal
+        }
+
+        fn penalty_option(&self, state: &MatchState) -> PenaltyOption {
+            let mut touch = state.pos;
+            touch.advance(TOUCH_KICK_GAIN, &state.is_home);
+
+            let goal = 3. * DataDriven::kick_chance(state.kick_difficulty());
+            let line_out = DataDriven::line_out_points(&touch, &state.is_home);
+
+            // Three points are no use when more than a converted try behind late on
+            let chasing = state.minute >= LATE_MINUTE && state.score_diff < -7;
+            if goal >= line_out && !chasing {
+                PenaltyOption::KickAtGoal
+            } else {
+                PenaltyOption::KickToTouch
+            }
+        }
+
+        fn phase_option(&self, state: &MatchState) -> PhaseOption {
+            let drop_goal = 3. * DataDriven::kick_chance(state.kick_difficulty());
+            let run = DataDriven::line_out_points(&state.pos, &state.is_home);
+
+            if state.late_and_close() && drop_goal > run {
+                PhaseOption::DropGoal
+            } else if state.pos.in_own_22(&state.is_home) {
+                PhaseOption::Kick
+            } else {
+                PhaseOption::Run
+            }
+        }
+
+        // Protect a lead with fresh legs, chase a game with the starters for longer
+        fn replacements(&self, state: &MatchState) -> usize {
+            let wave = match state.score_diff {
+                d if d > 7 => 50,
+                d if d < -7 => 60,
+                _ => 55,
+            };
+            match state.minute {
+                m if m < wave => 0,
+                m if m < wave + 10 => FRONT_ROW_REPLACEMENTS,
+                _ => ALL_REPLACEMENTS,
+            }
+        }
+    }
+}
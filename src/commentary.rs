@@ -0,0 +1,454 @@
+// Module to turn match events into commentary
+
+pub mod commentary {
+    use crate::{
+        coach::coach::PenaltyOption,
+        events::events::{
+            BreakdownResult, LineOutResult, MaulResult, PassResult, PhaseResult, ScrumResult,
+        },
+        match_engine::match_engine::{EventKind, MatchEvent, MatchObserver, Side},
+        random_engine::rng_eng::{rand_index, RollResult},
+        team::team::Team,
+    };
+
+    // Carries shorter than this are not worth a mention
+    const BIG_CARRY_METRES: f32 = 8.;
+
+    // Every kind of line the commentator can say
+    // Templates can use {minute}, {team}, {opposition}, {player}, {other}, {metres},
+    // {home}, {away}, {home_score} and {away_score}
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Line {
+        Dramatic, // Prefix for critical successes
+        Calamity, // Prefix for critical fails
+        KickOff,
+        KickOffSpilled,
+        ScrumWon,
+        ScrumStolen,
+        ScrumPenalty,
+        ScrumFreeKick,
+        Number8PickUp,
+        LineOutWon,
+        LineOutMaul,
+        LineOutStolen,
+        LineOutNotStraight,
+        MaulHeld,
+        MaulPenalty,
+        MaulBallOut,
+        KnockOn,
+        ForwardPass,
+        Intercept,
+        BigCarry,
+        DominantTackle,
+        CoverTackle,
+        MissedTackle,
+        QuickBall,
+        Turnover,
+        BreakdownPenalty,
+        TerritoryKick,
+        TerritoryKickInPlay,
+        KickToTouch,
+        KickToTouchMissed,
+        AdvantagePlayed,
+        AdvantageOver,
+        PenaltyKickAtGoal,
+        PenaltyKickToTouch,
+        PenaltyTapAndGo,
+        PenaltyScrum,
+        PenaltyGoal,
+        PenaltyMiss,
+        DropGoal,
+        DropGoalMiss,
+        Try,
+        PenaltyTry,
+        Conversion,
+        ConversionMiss,
+        DropOut,
+        Substitution,
+        YellowCard,
+        HalfTime,
+        FullTime,
+        ExtraTime,
+        SuddenDeath,
+        CompetitionKick,
+        CompetitionMiss,
+    }
+
+    // Style of commentary, a set of templates for every line
+    pub trait Style {
+        fn templates(&self, line: Line) -> &'static [&'static str];
+    }
+
+    // Classic
+    // Measured commentary from the gantry
+    pub struct Classic;
+
+    impl Style for Classic {
+        fn templates(&self, line: Line) -> &'static [&'static str] {
+            match line {
+                Line::Dramatic => &["Wonderful rugby! ", "Oh, that is special. "],
+                Line::Calamity => &["Oh dear. ", "That is a poor moment. "],
+                Line::KickOff => &[
+                    "{minute}' {team} kick off.",
+                    "{minute}' We're under way, {team} send it long.",
+                ],
+                Line::KickOffSpilled => &["{minute}' {opposition} spill the kick off."],
+                Line::ScrumWon => &[
+                    "{minute}' {team} win their own scrum.",
+                    "{minute}' Good clean ball for {team} at the scrum.",
+                ],
+                Line::ScrumStolen => &[
+                    "{minute}' {opposition} take it against the head!",
+                    "{minute}' {team} lose their own feed.",
+                ],
+                Line::ScrumPenalty => &[
+                    "{minute}' The referee blows for {team} at the scrum.",
+                    "{minute}' Scrum penalty to {team}.",
+                ],
+                Line::ScrumFreeKick => &["{minute}' Free kick at the scrum to {team}."],
+                Line::Number8PickUp => &["{minute}' The number 8 picks up for {team}."],
+                Line::LineOutWon => &[
+                    "{minute}' {team} take their line out.",
+                    "{minute}' Clean take for {team} off the top.",
+                ],
+                Line::LineOutMaul => &[
+                    "{minute}' {team} catch and set the maul.",
+                    "{minute}' Here comes the {team} driving maul.",
+                ],
+                Line::LineOutStolen => &["{minute}' {opposition} steal the line out!"],
+                Line::LineOutNotStraight => &["{minute}' The throw is not straight from {team}."],
+                Line::MaulHeld => &[
+                    "{minute}' {opposition} hold the maul up, turnover.",
+                    "{minute}' The {team} maul grinds to a halt.",
+                ],
+                Line::MaulPenalty => &["{minute}' {opposition} bring the maul down illegally."],
+                Line::MaulBallOut => &["{minute}' {team} use the ball from the back of the maul."],
+                Line::KnockOn => &[
+                    "{minute}' Knocked on by {team}.",
+                    "{minute}' {team} spill it forward.",
+                ],
+                Line::ForwardPass => &["{minute}' That pass from {team} went forward."],
+                Line::Intercept => &[
+                    "{minute}' Intercepted! {opposition} pick off the pass.",
+                    "{minute}' {opposition} read that pass and take it.",
+                ],
+                Line::BigCarry => &[
+                    "{minute}' {player} makes {metres} metres for {team}.",
+                    "{minute}' Strong carry from {player}, {metres} metres.",
+                ],
+                Line::DominantTackle => &[
+                    "{minute}' Big hit! {opposition} drive {team} backwards.",
+                    "{minute}' Dominant tackle from {opposition}.",
+                ],
+                Line::CoverTackle => &["{minute}' The cover defence of {opposition} scrambles back."],
+                Line::MissedTackle => &["{minute}' {player} misses a tackle."],
+                Line::QuickBall => &["{minute}' Quick ball for {team}."],
+                Line::Turnover => &[
+                    "{minute}' Turnover! {opposition} win it at the breakdown.",
+                    "{minute}' {opposition} get a hand on the ball at the ruck.",
+                ],
+                Line::BreakdownPenalty => &["{minute}' Penalty at the breakdown to {team}."],
+                Line::TerritoryKick => &[
+                    "{minute}' {team} kick for touch.",
+                    "{minute}' {team} find touch.",
+                ],
+                Line::TerritoryKickInPlay => &["{minute}' {team} kick, but it stays in play."],
+                Line::KickToTouch => &["{minute}' {team} kick the penalty to touch."],
+                Line::KickToTouchMissed => &["{minute}' {team} miss touch with the penalty."],
+                Line::AdvantagePlayed => &["{minute}' Advantage {team}."],
+                Line::AdvantageOver => &["{minute}' Advantage over."],
+                Line::PenaltyKickAtGoal => &["{minute}' {team} point at the posts."],
+                Line::PenaltyKickToTouch => &["{minute}' {team} will go to the corner."],
+                Line::PenaltyTapAndGo => &["{minute}' Quick tap from {team}!"],
+                Line::PenaltyScrum => &["{minute}' {team} opt for the scrum."],
+                Line::PenaltyGoal => &[
+                    "{minute}' {player} slots the penalty. {home} {home_score} - {away_score} {away}.",
+                ],
+                Line::PenaltyMiss => &["{minute}' {player} pushes the penalty wide."],
+                Line::DropGoal => &[
+                    "{minute}' Drop goal! {player} knocks it over. {home} {home_score} - {away_score} {away}.",
+                ],
+                Line::DropGoalMiss => &["{minute}' {player} snatches at the drop goal, wide."],
+                Line::Try => &[
+                    "{minute}' TRY! {player} goes over for {team}.",
+                    "{minute}' {player} touches down for {team}!",
+                ],
+                Line::PenaltyTry => &["{minute}' Penalty try awarded to {team}."],
+                Line::Conversion => &[
+                    "{minute}' {player} adds the extras. {home} {home_score} - {away_score} {away}.",
+                ],
+                Line::ConversionMiss => &[
+                    "{minute}' {player} misses the conversion. {home} {home_score} - {away_score} {away}.",
+                ],
+                Line::DropOut => &["{minute}' {team} restart with a 22 drop out."],
+                Line::Substitution => &["{minute}' {team} change: {player} on for {other}."],
+                Line::YellowCard => &["{minute}' Yellow card. {player} of {team} goes to the sin bin."],
+                Line::HalfTime => &["Half time: {home} {home_score} - {away_score} {away}."],
+                Line::FullTime => &["Full time: {home} {home_score} - {away_score} {away}."],
+                Line::ExtraTime => &["All square, we go to extra time."],
+                Line::SuddenDeath => &["Still level. Next score wins."],
+                Line::CompetitionKick => &["{player} puts it over for {team}."],
+                Line::CompetitionMiss => &["{player} misses for {team}."],
+            }
+        }
+    }
+
+    // Excitable
+    // Commentary from someone who has had too much coffee
+    pub struct Excitable;
+
+    impl Style for Excitable {
+        fn templates(&self, line: Line) -> &'static [&'static str] {
+            match line {
+                Line::Dramatic => &["UNBELIEVABLE! ", "ARE YOU SEEING THIS?! "],
+                Line::Calamity => &["NO NO NO! ", "DISASTER! "],
+                Line::KickOff => &["{minute}' AND WE'RE OFF! {team} get it going!"],
+                Line::KickOffSpilled => {
+                    &["{minute}' {opposition} drop the kick off! Butterfingers!"]
+                }
+                Line::ScrumWon => &["{minute}' {team} hold firm at the scrum!"],
+                Line::ScrumStolen => &["{minute}' AGAINST THE HEAD! {opposition} rob {team}!"],
+                Line::ScrumPenalty => &["{minute}' The scrum creaks and it's a penalty to {team}!"],
+                Line::ScrumFreeKick => &["{minute}' Free kick {team}!"],
+                Line::Number8PickUp => &["{minute}' Number 8 off the base for {team}, here we go!"],
+                Line::LineOutWon => &["{minute}' {team} claim it in the air!"],
+                Line::LineOutMaul => &["{minute}' MAUL! {team} set the drive!"],
+                Line::LineOutStolen => &["{minute}' STOLEN! {opposition} pinch the throw!"],
+                Line::LineOutNotStraight => &["{minute}' Crooked throw from {team}! Ouch!"],
+                Line::MaulHeld => &["{minute}' {opposition} stop the maul dead!"],
+                Line::MaulPenalty => &["{minute}' {opposition} collapse the maul, penalty!"],
+                Line::MaulBallOut => &["{minute}' Ball's out of the maul for {team}!"],
+                Line::KnockOn => &["{minute}' Knock on {team}! Hands of stone!"],
+                Line::ForwardPass => &["{minute}' FORWARD! Way forward from {team}!"],
+                Line::Intercept => &["{minute}' PICKED OFF! {opposition} intercept!"],
+                Line::BigCarry => &["{minute}' {player} is rampaging! {metres} metres!"],
+                Line::DominantTackle => &["{minute}' BOOM! {opposition} smash {team} backwards!"],
+                Line::CoverTackle => {
+                    &["{minute}' WHAT A TACKLE! The cover from {opposition} saves it!"]
+                }
+                Line::MissedTackle => &["{minute}' {player} is left grasping at thin air!"],
+                Line::QuickBall => &["{minute}' Lightning ball for {team}!"],
+                Line::Turnover => &["{minute}' JACKAL! {opposition} steal it!"],
+                Line::BreakdownPenalty => &["{minute}' Penalty {team}! The ruck was a mess!"],
+                Line::TerritoryKick => &["{minute}' {team} boot it into touch!"],
+                Line::TerritoryKickInPlay => &["{minute}' {team} kick and it's still alive!"],
+                Line::KickToTouch => &["{minute}' {team} launch the penalty downfield!"],
+                Line::KickToTouchMissed => &["{minute}' {team} miss touch! Schoolboy stuff!"],
+                Line::AdvantagePlayed => &["{minute}' Advantage {team}, play on!"],
+                Line::AdvantageOver => &["{minute}' Advantage over!"],
+                Line::PenaltyKickAtGoal => &["{minute}' {team} go for the posts!"],
+                Line::PenaltyKickToTouch => &["{minute}' {team} go for the corner! Brave!"],
+                Line::PenaltyTapAndGo => &["{minute}' TAP AND GO! {team} are in a hurry!"],
+                Line::PenaltyScrum => {
+                    &["{minute}' {team} want a scrum! The forwards are loving this!"]
+                }
+                Line::PenaltyGoal => {
+                    &["{minute}' {player} NAILS IT! {home} {home_score} - {away_score} {away}!"]
+                }
+                Line::PenaltyMiss => &["{minute}' {player} MISSES! Oh, the agony!"],
+                Line::DropGoal => {
+                    &["{minute}' DROP GOAL! {player}! {home} {home_score} - {away_score} {away}!"]
+                }
+                Line::DropGoalMiss => &["{minute}' {player} tries the drop goal... NO!"],
+                Line::Try => &[
+                    "{minute}' TRYYYY! {player} SCORES FOR {team}!",
+                    "{minute}' OVER THE LINE! {player}!",
+                ],
+                Line::PenaltyTry => &["{minute}' PENALTY TRY! Seven points to {team}!"],
+                Line::Conversion => {
+                    &["{minute}' {player} converts! {home} {home_score} - {away_score} {away}!"]
+                }
+                Line::ConversionMiss => &[
+                    "{minute}' {player} can't convert! {home} {home_score} - {away_score} {away}!",
+                ],
+                Line::DropOut => &["{minute}' {team} drop out from the 22!"],
+                Line::Substitution => {
+                    &["{minute}' Fresh legs for {team}! {player} replaces {other}!"]
+                }
+                Line::YellowCard => {
+                    &["{minute}' YELLOW! {player} is off and {team} are down to fourteen!"]
+                }
+                Line::HalfTime => &["HALF TIME! {home} {home_score} - {away_score} {away}!"],
+                Line::FullTime => &["IT'S ALL OVER! {home} {home_score} - {away_score} {away}!"],
+                Line::ExtraTime => &["WE'RE GOING TO EXTRA TIME!"],
+                Line::SuddenDeath => &["SUDDEN DEATH! Next score takes it!"],
+                Line::CompetitionKick => &["{player} SCORES for {team}!"],
+                Line::CompetitionMiss => &["{player} MISSES! {team} are in trouble!"],
+            }
+        }
+    }
+
+    // Commentary
+    // Follows a match and turns each event into a line of commentary
+    pub struct Commentary {
+        style: Box<dyn Style>,
+        pub live: bool, // Print each line as it happens
+        pub lines: Vec<String>,
+    }
+
+    impl Commentary {
+        pub fn new(style: Box<dyn Style>) -> Commentary {
+            Commentary {
+                style,
+                live: true,
+                lines: Vec::new(),
+            }
+        }
+
+        // Commentary for a single event, routine events are skipped
+        pub fn line(&self, event: &MatchEvent, home: &Team, away: &Team) -> Option<String> {
+            let (line, side) = pick_line(event)?;
+            let templates = self.style.templates(line);
+            let mut text = templates[rand_index(templates.len())].to_string();
+
+            // Dress up the big moments
+            let prefix = match event.roll {
+                RollResult::CriticalSuccess => Some(Line::Dramatic),
+                RollResult::CriticalFail => Some(Line::Calamity),
+                RollResult::Flat => None,
+            };
+            if let Some(prefix) = prefix {
+                let prefixes = self.style.templates(prefix);
+                text = text.replacen(
+                    "' ",
+                    &format!("' {}", prefixes[rand_index(prefixes.len())]),
+                    1,
+                );
+            }
+
+            let (team, opposition) = match side {
+                Side::Home => (home, away),
+                Side::Away => (away, home),
+            };
+            let (player, other) = match event.kind {
+                EventKind::Substitution(off, on) => (Some(on), Some(off)),
+                EventKind::Carry(player, _)
+                | EventKind::Tackle(player, _)
+                | EventKind::Try(player)
+                | EventKind::YellowCard(player)
+                | EventKind::Conversion(player, _)
+                | EventKind::PenaltyGoal(player, _)
+                | EventKind::DropGoal(player, _)
+                | EventKind::KickingCompetition(player, _) => (Some(player), None),
+                _ => (None, None),
+            };
+            let name =
+                |i: Option<usize>| i.map(|i| team.players[i].name.clone()).unwrap_or_default();
+            let metres = match event.kind {
+                EventKind::Carry(_, metres) => metres,
+                _ => 0.,
+            };
+
+            Some(
+                text.replace("{minute}", &event.minute().to_string())
+                    .replace("{team}", &team.name)
+                    .replace("{opposition}", &opposition.name)
+                    .replace("{player}", &name(player))
+                    .replace("{other}", &name(other))
+                    .replace("{metres}", &format!("{:.0}", metres))
+                    .replace("{home}", &home.name)
+                    .replace("{away}", &away.name)
+                    .replace("{home_score}", &home.score.to_string())
+                    .replace("{away_score}", &away.score.to_string()),
+            )
+        }
+    }
+
+    impl MatchObserver for Commentary {
+        fn on_event(&mut self, event: &MatchEvent, home: &Team, away: &Team) {
+            if let Some(line) = self.line(event, home, away) {
+                if self.live {
+                    println!("{}", line);
+                }
+                self.lines.push(line);
+            }
+        }
+    }
+
+    // Line for an event, if it is worth commenting on, and the side it is about
+    fn pick_line(event: &MatchEvent) -> Option<(Line, Side)> {
+        // Set piece and breakdown awards can go against the side in possession
+        let side = match event.kind {
+            EventKind::Scrum(ScrumResult::PenaltyDefence | ScrumResult::FreeKickDefence)
+            | EventKind::Breakdown(BreakdownResult::PenaltyDefence) => event.side.other(),
+            _ => event.side,
+        };
+        let line = match event.kind {
+            EventKind::KickOff => match event.roll {
+                RollResult::CriticalFail => Line::KickOffSpilled,
+                _ => Line::KickOff,
+            },
+            EventKind::Scrum(res) => match res {
+                ScrumResult::AttackBall => Line::ScrumWon,
+                ScrumResult::DefenceBall => Line::ScrumStolen,
+                ScrumResult::Number8PickUp => Line::Number8PickUp,
+                ScrumResult::PenaltyAttack | ScrumResult::PenaltyDefence => Line::ScrumPenalty,
+                ScrumResult::FreeKickAttack | ScrumResult::FreeKickDefence => Line::ScrumFreeKick,
+            },
+            EventKind::LineOut(res) => match res {
+                LineOutResult::CleanCatch => Line::LineOutWon,
+                LineOutResult::Maul => Line::LineOutMaul,
+                LineOutResult::Stolen => Line::LineOutStolen,
+                LineOutResult::NotStraight => Line::LineOutNotStraight,
+            },
+            EventKind::Maul(res) => match res {
+                MaulResult::Turnover => Line::MaulHeld,
+                MaulResult::Penalty => Line::MaulPenalty,
+                MaulResult::BallOut => Line::MaulBallOut,
+                // Tries are called when they are awarded
+                MaulResult::Try | MaulResult::PenaltyTry => return None,
+            },
+            EventKind::Pass(res) | EventKind::Phase(PhaseResult::Handling(res)) => match res {
+                PassResult::KnockOn => Line::KnockOn,
+                PassResult::ForwardPass => Line::ForwardPass,
+                PassResult::Intercept => Line::Intercept,
+                PassResult::Clean => return None,
+            },
+            EventKind::Phase(PhaseResult::DominantTackle) => Line::DominantTackle,
+            EventKind::Phase(PhaseResult::CoverTackle) => Line::CoverTackle,
+            EventKind::Phase(_) => return None,
+            EventKind::Carry(_, metres) if metres >= BIG_CARRY_METRES => Line::BigCarry,
+            EventKind::Carry(..) => return None,
+            EventKind::Tackle(_, false) => Line::MissedTackle,
+            EventKind::Tackle(_, true) => return None,
+            EventKind::Breakdown(res) => match (res, event.roll) {
+                (BreakdownResult::Retained, RollResult::CriticalSuccess) => Line::QuickBall,
+                (BreakdownResult::Retained, _) => return None,
+                (BreakdownResult::Turnover, _) => Line::Turnover,
+                (BreakdownResult::PenaltyAttack | BreakdownResult::PenaltyDefence, _) => {
+                    Line::BreakdownPenalty
+                }
+            },
+            EventKind::TerritoryKick(true) => Line::TerritoryKick,
+            EventKind::TerritoryKick(false) => Line::TerritoryKickInPlay,
+            EventKind::KickToTouch(true) => Line::KickToTouch,
+            EventKind::KickToTouch(false) => Line::KickToTouchMissed,
+            EventKind::AdvantagePlayed => Line::AdvantagePlayed,
+            EventKind::AdvantageOver => Line::AdvantageOver,
+            EventKind::PenaltyOption(option) => match option {
+                PenaltyOption::KickAtGoal => Line::PenaltyKickAtGoal,
+                PenaltyOption::KickToTouch => Line::PenaltyKickToTouch,
+                PenaltyOption::TapAndGo => Line::PenaltyTapAndGo,
+                PenaltyOption::Scrum => Line::PenaltyScrum,
+            },
+            EventKind::PenaltyGoal(_, true) => Line::PenaltyGoal,
+            EventKind::PenaltyGoal(_, false) => Line::PenaltyMiss,
+            EventKind::DropGoal(_, true) => Line::DropGoal,
+            EventKind::DropGoal(_, false) => Line::DropGoalMiss,
+            EventKind::Conversion(_, true) => Line::Conversion,
+            EventKind::Conversion(_, false) => Line::ConversionMiss,
+            EventKind::Try(_) => Line::Try,
+            EventKind::PenaltyTry => Line::PenaltyTry,
+            EventKind::DropOut => Line::DropOut,
+            EventKind::Substitution(..) => Line::Substitution,
+            EventKind::YellowCard(_) => Line::YellowCard,
+            EventKind::HalfTime => Line::HalfTime,
+            EventKind::FullTime => Line::FullTime,
+            EventKind::ExtraTime => Line::ExtraTime,
+            EventKind::SuddenDeath => Line::SuddenDeath,
+            EventKind::KickingCompetition(_, true) => Line::CompetitionKick,
+            EventKind::KickingCompetition(_, false) => Line::CompetitionMiss,
+        };
+        Some((line, side))
+    }
+}
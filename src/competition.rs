@@ -0,0 +1,449 @@
+// Module to run a league between many teams
+
+pub mod competition {
+    use std::{cmp::Reverse, fmt::Write};
+
+    use crate::{
+        finance::finance::{take_gate, wage_bill, SalaryCap},
+        match_engine::match_engine::Match,
+        random_engine::rnd_name::NameGenerator,
+        report::report::MatchReport,
+        team::team::{pair_mut, Team},
+        training::training::train_week,
+    };
+
+    // League points
+    const WIN_POINTS: i32 = 4;
+    const DRAW_POINTS: i32 = 2;
+    // Bonus point for scoring this many tries, win or lose
+    const TRY_BONUS_TRIES: u32 = 4;
+    // Bonus point for losing by this much or less
+    const LOSING_BONUS_MARGIN: i32 = 7;
+
+    // Result of a played fixture
+    #[derive(Clone, Copy)]
+    pub struct MatchResult {
+        pub home_score: i32,
+        pub away_score: i32,
+        pub home_tries: u32, // Including penalty tries
+        pub away_tries: u32,
+    }
+
+    // Match between two teams, by index into the competition
+    pub struct Fixture {
+        pub round: usize,
+        pub home: usize,
+        pub away: usize,
+        pub result: Option<MatchResult>,
+    }
+
+    // Row of the league table
+    #[derive(Clone, Default)]
+    pub struct Standing {
+        pub team: usize,
+        pub played: u32,
+        pub won: u32,
+        pub drawn: u32,
+        pub lost: u32,
+        pub points_for: i32,
+        pub points_against: i32,
+        pub tries_for: u32,
+        pub tries_against: u32,
+        pub try_bonus: u32,
+        pub losing_bonus: u32,
+        pub deducted: u32, // Points taken off for breaching the salary cap
+        pub points: i32,   // Can go below zero when more is deducted than earned
+    }
+
+    impl Standing {
+        pub fn points_difference(&self) -> i32 {
+            self.points_for - self.points_against
+        }
+
+        // Add a result from this team's point of view
+        fn add_result(&mut self, scored: i32, conceded: i32, tries: u32, tries_against: u32) {
+            self.played += 1;
+            self.points_for += scored;
+            self.points_against += conceded;
+            self.tries_for += tries;
+            self.tries_against += tries_against;
+
+            match scored - conceded {
+                m if m > 0 => {
+                    self.won += 1;
+                    self.points += WIN_POINTS;
+                }
+                0 => {
+                    self.drawn += 1;
+                    self.points += DRAW_POINTS;
+                }
+                m => {
+                    self.lost += 1;
+                    if -m <= LOSING_BONUS_MARGIN {
+                        self.losing_bonus += 1;
+                        self.points += 1;
+                    }
+                }
+            }
+            if tries >= TRY_BONUS_TRIES {
+                self.try_bonus += 1;
+                self.points += 1;
+            }
+        }
+    }
+
+    // Competition
+    // Round robin league, every team plays every other home and away
+    pub struct Competition {
+        pub name: String,
+        pub teams: Vec<Team>,
+        pub fixtures: Vec<Fixture>,
+        pub salary_cap: Option<SalaryCap>,
+        pub deductions: Vec<u32>, // Points deducted from each team this season
+    }
+
+    impl Competition {
+        // No two players in the competition share a name
+        pub fn new(name: &str, mut teams: Vec<Team>) -> Competition {
+            let mut names = NameGenerator::new();
+            for team in teams.iter_mut() {
+                team.claim_names(&mut names);
+            }
+            let fixtures = round_robin(teams.len());
+            Competition {
+                name: name.to_string(),
+                deductions: vec![0; teams.len()],
+                teams,
+                fixtures,
+                salary_cap: None,
+            }
+        }
+
+        // Clear the results and draw the fixtures again
+        // Teams over the salary cap start the season with a deduction
+        pub fn new_season(&mut self) {
+            self.fixtures = round_robin(self.teams.len());
+            self.deductions = self
+                .teams
+                .iter()
+                .map(|team| match self.salary_cap {
+                    Some(cap) => cap.penalty(wage_bill(team)),
+                    None => 0,
+                })
+                .collect();
+        }
+
+        pub fn rounds(&self) -> usize {
+            self.fixtures.iter().map(|x| x.round + 1).max().unwrap_or(0)
+        }
+
+        // First round with fixtures still to play
+        pub fn next_round(&self) -> Option<usize> {
+            self.fixtures
+                .iter()
+                .filter(|x| x.result.is_none())
+                .map(|x| x.round)
+                .min()
+        }
+
+        // Train for a week, then play every unplayed fixture in a round
+        pub fn play_round(&mut self, round: usize) {
+            for team in self.teams.iter_mut() {
+                train_week(team);
+            }
+            for i in 0..self.fixtures.len() {
+                let fixture = &self.fixtures[i];
+                if fixture.round == round && fixture.result.is_none() {
+                    let (home, away) = (fixture.home, fixture.away);
+                    self.fixtures[i].result = Some(self.play_fixture(home, away));
+                }
+            }
+        }
+
+        // Play the rest of the season
+        pub fn play_all(&mut self) {
+            while let Some(round) = self.next_round() {
+                self.play_round(round);
+            }
+        }
+
+        // Play a match, count the games played and name the same lineups for the next one
+        // The home team takes the gate
+        fn play_fixture(&mut self, home: usize, away: usize) -> MatchResult {
+            let points = self.table().iter().find(|x| x.team == home).unwrap().points;
+            let (home, away) = pair_mut(&mut self.teams, home, away);
+            take_gate(home, points.max(0) as u32);
+            let lineups = (home.lineup(), away.lineup());
+            let starters = (home.on_field(), away.on_field());
+
+            let mut game = Match::new(home, away);
+            game.play();
+            let report = MatchReport::from_match(&game);
+            let stats = &report.stats;
+            let result = MatchResult {
+                home_score: game.home.score,
+                away_score: game.away.score,
+                home_tries: stats.home.tries + stats.home.penalty_tries,
+                away_tries: stats.away.tries + stats.away.penalty_tries,
+            };
+
+            home.add_game(&starters.0);
+            away.add_game(&starters.1);
+            home.add_ratings(&report.home_ratings);
+            away.add_ratings(&report.away_ratings);
+            home.restore_lineup(&lineups.0);
+            away.restore_lineup(&lineups.1);
+            result
+        }
+
+        // League table, ordered by points, then points difference, then tries scored
+        pub fn table(&self) -> Vec<Standing> {
+            let mut table = (0..self.teams.len())
+                .map(|team| Standing {
+                    team,
+                    ..Standing::default()
+                })
+                .collect::<Vec<Standing>>();
+
+            for fixture in self.fixtures.iter() {
+                if let Some(res) = fixture.result {
+                    table[fixture.home].add_result(
+                        res.home_score,
+                        res.away_score,
+                        res.home_tries,
+                        res.away_tries,
+                    );
+                    table[fixture.away].add_result(
+                        res.away_score,
+                        res.home_score,
+                        res.away_tries,
+                        res.home_tries,
+                    );
+                }
+            }
+
+            for row in table.iter_mut() {
+                row.deducted = self.deductions.get(row.team).copied().unwrap_or(0);
+                row.points -= row.deducted as i32;
+            }
+
+            table.sort_by_key(|x| Reverse((x.points, x.points_difference(), x.tries_for)));
+            table
+        }
+
+        // League table as plain text
+        pub fn table_text(&self) -> String {
+            let mut out = String::new();
+            let _ = writeln!(out, "{}", self.name);
+            let _ = writeln!(
+                out,
+                "{:>2} {:<25} {:>2} {:>2} {:>2} {:>2} {:>4} {:>4} {:>4} {:>2} {:>2} {:>3} {:>3}",
+                "", "Team", "P", "W", "D", "L", "PF", "PA", "PD", "TB", "LB", "Ded", "Pts"
+            );
+            for (i, row) in self.table().iter().enumerate() {
+                let _ = writeln!(
+                    out,
+                    "{:>2} {:<25} {:>2} {:>2} {:>2} {:>2} {:>4} {:>4} {:>4} {:>2} {:>2} {:>3} {:>3}",
+                    i + 1,
+                    self.teams[row.team].name,
+                    row.played,
+                    row.won,
+                    row.drawn,
+                    row.lost,
+                    row.points_for,
+                    row.points_against,
+                    row.points_difference(),
+                    row.try_bonus,
+                    row.losing_bonus,
+                    row.deducted,
+                    row.points
+                );
+            }
+            out
+        }
+    }
+
+    // Home and away fixtures for every pair of teams using the circle method
+    // The last team stays put while the rest turn around it, one team sits out each round when
+    // the number of teams is odd
+    fn round_robin(teams: usize) -> Vec<Fixture> {
+        let n = teams + teams % 2;
+        if n < 2 {
+            return Vec::new();
+        }
+        let turning = n - 1;
+
+        let mut fixtures = Vec::new();
+        for round in 0..turning {
+            // Home and away alternate by round, nobody has more than two in a row
+            // The fixed team swaps every round, the others every step out from the round's team
+            let mut pairs = vec![match round % 2 {
+                0 => (round, turning),
+                _ => (turning, round),
+            }];
+            for k in 1..n / 2 {
+                let up = (round + k) % turning;
+                let down = (round + turning - k) % turning;
+                pairs.push(match k % 2 {
+                    1 => (up, down),
+                    _ => (down, up),
+                });
+            }
+
+            for (home, away) in pairs.into_iter().filter(|&(a, b)| a < teams && b < teams) {
+                fixtures.push(Fixture {
+                    round,
+                    home,
+                    away,
+                    result: None,
+                });
+                // Return fixture in the second half of the season
+                fixtures.push(Fixture {
+                    round: round + turning,
+                    home: away,
+                    away: home,
+                    result: None,
+                });
+            }
+        }
+        fixtures.sort_by_key(|x| x.round);
+        fixtures
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn names_are_unique_across_the_league() {
+            let teams = (0..6).map(|_| Team::new()).collect::<Vec<Team>>();
+            let league = Competition::new("Test League", teams);
+            let names = league
+                .teams
+                .iter()
+                .flat_map(|x| x.players.iter().map(|p| p.name.clone()))
+                .collect::<Vec<String>>();
+            let unique = names.iter().collect::<std::collections::HashSet<&String>>();
+            assert_eq!(unique.len(), names.len());
+        }
+
+        // Number of rounds in each half of the season
+        fn leg_rounds(teams: usize) -> usize {
+            teams + teams % 2 - 1
+        }
+
+        #[test]
+        fn every_pair_meets_once_per_leg() {
+            for teams in 2..=9 {
+                let fixtures = round_robin(teams);
+                let legs = leg_rounds(teams);
+                for a in 0..teams {
+                    for b in 0..teams {
+                        if a == b {
+                            continue;
+                        }
+                        let meetings = |first_leg: bool| {
+                            fixtures
+                                .iter()
+                                .filter(|x| (x.round < legs) == first_leg)
+                                .filter(|x| {
+                                    (x.home, x.away) == (a, b) || (x.home, x.away) == (b, a)
+                                })
+                                .count()
+                        };
+                        assert_eq!(meetings(true), 1, "{} teams, {} v {}", teams, a, b);
+                        assert_eq!(meetings(false), 1, "{} teams, {} v {}", teams, a, b);
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn home_and_away_alternate() {
+            for teams in 2..=10 {
+                let fixtures = round_robin(teams);
+                for team in 0..teams {
+                    // Home or away in each first leg game, in order
+                    let venues = fixtures
+                        .iter()
+                        .filter(|x| x.round < leg_rounds(teams))
+                        .filter(|x| x.home == team || x.away == team)
+                        .map(|x| x.home == team)
+                        .collect::<Vec<bool>>();
+                    let longest = venues
+                        .chunk_by(|a, b| a == b)
+                        .map(|x| x.len())
+                        .max()
+                        .unwrap_or(0);
+                    assert!(longest <= 2, "{} teams, team {}: {:?}", teams, team, venues);
+                }
+            }
+        }
+
+        #[test]
+        fn no_team_plays_twice_in_a_round() {
+            for teams in 2..=9 {
+                let fixtures = round_robin(teams);
+                for round in 0..2 * leg_rounds(teams) {
+                    let mut playing = fixtures
+                        .iter()
+                        .filter(|x| x.round == round)
+                        .flat_map(|x| [x.home, x.away])
+                        .collect::<Vec<usize>>();
+                    let total = playing.len();
+                    playing.sort();
+                    playing.dedup();
+                    assert_eq!(playing.len(), total, "{} teams, round {}", teams, round);
+                }
+            }
+        }
+
+        #[test]
+        fn try_bonus_at_four_tries() {
+            let mut standing = Standing::default();
+            standing.add_result(20, 30, 3, 4);
+            assert_eq!(standing.try_bonus, 0);
+            standing.add_result(30, 20, 4, 3);
+            assert_eq!(standing.try_bonus, 1);
+            assert_eq!(standing.points, WIN_POINTS + 1);
+        }
+
+        #[test]
+        fn losing_bonus_within_seven_points() {
+            let mut standing = Standing::default();
+            standing.add_result(13, 20, 0, 0);
+            assert_eq!(standing.losing_bonus, 1);
+            assert_eq!(standing.points, 1);
+            standing.add_result(12, 20, 0, 0);
+            assert_eq!(standing.losing_bonus, 1);
+            assert_eq!(standing.points, 1);
+            // A draw is not a loss
+            standing.add_result(20, 20, 0, 0);
+            assert_eq!(standing.losing_bonus, 1);
+            assert_eq!(standing.points, 1 + DRAW_POINTS);
+        }
+
+        #[test]
+        fn deductions_come_off_the_table() {
+            let mut league = Competition::new("Test", vec![Team::new(), Team::new()]);
+            league.fixtures[0].result = Some(MatchResult {
+                home_score: 20,
+                away_score: 10,
+                home_tries: 2,
+                away_tries: 1,
+            });
+            let winner = league.fixtures[0].home;
+            league.deductions[winner] = 3;
+            league.deductions[1 - winner] = 5;
+
+            let table = league.table();
+            let row = |team: usize| table.iter().find(|x| x.team == team).unwrap();
+            assert_eq!(row(winner).deducted, 3);
+            assert_eq!(row(winner).points, WIN_POINTS - 3);
+            // A deduction bigger than the points earned leaves a negative total
+            assert_eq!(row(1 - winner).deducted, 5);
+            assert_eq!(row(1 - winner).points, -5);
+            assert_eq!(table.last().unwrap().team, 1 - winner);
+        }
+    }
+}
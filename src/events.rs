@@ -3,23 +3,132 @@
 pub mod events {
     use crate::{
         pitch::pitch::PitchPosition,
-        player::player::{Player, Position},
-        random_engine::rng_eng::{AttributeTypes, RollResult, RollType},
+        player::player::{Player, Position, BACKS, BACK_ROW, FORWARDS, FRONT_ROW, SECOND_ROW},
+        random_engine::rng_eng::{AttributeTypes, RollResult},
         team::team::Team,
     };
 
     // Critical values for a scrum event
     const SCRUM_CRIT: i32 = 10;
     const SCRUM_PUT_IN_ADV: i32 = 10;
+    // Push differential where the beaten pack is penalised
+    const SCRUM_PENALTY_CRIT: i32 = 20;
+    // Front row weighting at the engagement
+    const SCRUM_FRONT_ROW_WEIGHT: i32 = 2;
+    // Collapsed scrums reset before the referee loses patience
+    const MAX_SCRUM_RESETS: i32 = 2;
+    // Charisma a leader needs to talk the referee out of a penalty at the breakdown
+    const REFEREE_BENEFIT: i32 = 18;
+    // Pack weight worth a point at the scrum and the maul
+    const SCRUM_KG_PER_POINT: u32 = 10;
+    const MAUL_KG_PER_POINT: u32 = 10;
+
+    // Pressure on a pack defending near its own line
+    const SCRUM_5M_PRESSURE: i32 = 6;
+    const SCRUM_22_PRESSURE: i32 = 3;
 
     // Critical values for a line out event
     const LINE_OUT_TROW_CRIT: i32 = 10;
+    // Defence failing to read the call contests at a deficit
+    const LINE_OUT_MISREAD: i32 = 8;
+    // Jumper height worth a point in the air
+    const LINE_OUT_CM_PER_POINT: u32 = 4;
+    // Longer throws are harder to hit
+    const LINE_OUT_MIDDLE_THROW: i32 = 2;
+    const LINE_OUT_BACK_THROW: i32 = 4;
+    // Players allowed in a line out
+    const LINE_OUT_MIN_NUMBERS: usize = 2;
+    const LINE_OUT_MAX_NUMBERS: usize = 7;
+    // Order of the forwards in the line, front to back
+    // The hooker throws so never stands in the line
+    const LINE_OUT_ORDER: [Position; 7] = [
+        Position::LooseHead,
+        Position::SecondRow1,
+        Position::TightHead,
+        Position::SecondRow2,
+        Position::BlindSideFlanker,
+        Position::Number8,
+        Position::OpenSideFlanker,
+    ];
 
     // Critical values for a maul event
     const MAUL_CRIT: i32 = 10;
+    // Margins of the group totals where the maul is held up dead, or pulled down by the defence
+    const MAUL_HELD_UP_MARGIN: i32 = 30;
+    const MAUL_COLLAPSE_MARGIN: i32 = 40;
+    // Attacking pack picks the moment to drive
+    const MAUL_DRIVE_ADV: i32 = 8;
+    // Strength differential for each metre gained
+    const MAUL_METRE_DIV: i32 = 5;
+    const MAUL_MAX_METRES: i32 = 5;
+    // Rounds before the ball has to come out
+    const MAUL_MAX_ROUNDS: i32 = 5;
+    // Stalls before the referee calls use it or lose it
+    const MAUL_MAX_STALLS: i32 = 2;
+    // Collapsing a maul this close to the line is a penalty try
+    const MAUL_PENALTY_TRY_DIST: f32 = 5.;
+
+    // Critical/Modifier values for passing
+    // Catch needed after the quality of the pass is added
+    const PASS_HANDLING_DIFF: i32 = 5;
+    // Passes are thrown slightly backwards
+    const PASS_DEPTH: f32 = 1.;
+
+    // Critical/Modifier values for open play
+    // Shape differential where the attack breaks the line
+    const LINE_BREAK_CRIT: i32 = 30;
+    const LINE_BREAK_METRES: f32 = 15.;
+    // Metres from a carry into contact
+    const CARRY_METRES: i32 = 2;
+    const CARRY_METRE_DIV: i32 = 8;
+    const CARRY_MAX_METRES: i32 = 5;
+    const DOMINANT_TACKLE_METRES: f32 = 2.;
+    // Weight difference worth a point in the tackle
+    const TACKLE_KG_PER_POINT: i32 = 10;
+    // Margin a tackler with better power to weight needs to drive the carrier back
+    const DOMINANT_TACKLE_MARGIN: i32 = 12;
+    // Dexterity needed to be on the shoulder of a line break
+    const SUPPORT_RUNNER_DIFF: i32 = 12;
+    // Players holding the defensive line, the back three sit in the cover
+    const DEFENSIVE_LINE: [Position; 7] = [
+        Position::ScrumHalf,
+        Position::FlyHalf,
+        Position::InsideCentre,
+        Position::OutsideCentre,
+        Position::BlindSideFlanker,
+        Position::OpenSideFlanker,
+        Position::Number8,
+    ];
+    const COVER_DEFENCE: [Position; 3] = [
+        Position::FullBack,
+        Position::LeftWinger,
+        Position::RightWinger,
+    ];
+    // Support runners looking for an offload after a line break
+    const SUPPORT_RUNNERS: [Position; 4] = [
+        Position::OpenSideFlanker,
+        Position::FullBack,
+        Position::OutsideCentre,
+        Position::ScrumHalf,
+    ];
+
+    // Critical values for the breakdown
+    // Defensive differential where the ball is turned over
+    const BREAKDOWN_TURNOVER_CRIT: i32 = 10;
+    // Attacking differential giving quick ball
+    const BREAKDOWN_QUICK_BALL: i32 = 10;
+    // Players committed to a ruck by default
+    pub const BREAKDOWN_NUMBERS: usize = 2;
 
     // Critical/Modifier values for kicking
-    const PENALTY_KICK_MOD: i32 = 1;
+    // Metres per point of a kick to touch
+    const TOUCH_KICK_METRES: i32 = 2;
+    const TOUCH_KICK_MISS_METRES: f32 = 10.;
+    const TOUCH_KICK_PERFECT_METRES: f32 = 50.;
+    // Line outs are formed no closer than 5m to the try line
+    const TOUCH_LINE_OUT_DIST: f32 = 5.;
+    // Wisdom read of the space, metres gained or lost against an average read
+    const TOUCH_KICK_AVERAGE_READ: i32 = 10;
     const DROP_KICK_SETUP_MOD: i32 = 5;
     const KICKER_PROTECTION: i32 = 10;
 
@@ -27,11 +136,13 @@ pub mod events {
 
     // tackle
     // Return a bool and the roll result
+    // The heavier player wins the collision, better power to weight makes a tackle dominant
     pub fn tackle(ball_carrier: &Player, tackler: &Player) -> (bool, RollResult) {
         // Ball carrier will make a dex check
         let (bc_score, bc_result) = ball_carrier.challange_roll(&AttributeTypes::Dexterity);
         // Tackler will make a strength check
         let (tk_score, tk_result) = tackler.challange_roll(&AttributeTypes::Strength);
+        let tk_score = tk_score + (tackler.mass() - ball_carrier.mass()) / TACKLE_KG_PER_POINT;
 
         match (bc_result, tk_result) {
             // Ball carrier 20, Tackler 2-19
@@ -51,6 +162,12 @@ pub mod events {
                 (bc_score < tk_score, RollResult::CriticalSuccess)
             }
             // Ball carrier 2-19, Tackler 2-19
+            (RollResult::Flat, RollResult::Flat)
+                if tk_score - bc_score >= DOMINANT_TACKLE_MARGIN
+                    && tackler.power_to_weight() > ball_carrier.power_to_weight() =>
+            {
+                (true, RollResult::CriticalSuccess)
+            }
             (RollResult::Flat, RollResult::Flat) => (bc_score < tk_score, RollResult::Flat),
             // Ball carrier 20, tackler 1
             (RollResult::CriticalSuccess, RollResult::CriticalFail) => {
@@ -63,64 +180,732 @@ pub mod events {
         }
     }
 
+    // Outcome of a completed scrum
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum ScrumResult {
+        AttackBall,      // Attacking team secures their own feed
+        DefenceBall,     // Ball won against the head or lost at the base
+        Number8PickUp,   // Number 8 picks up and carries from the base
+        PenaltyAttack,   // Penalty awarded to the attacking team
+        PenaltyDefence,  // Penalty awarded to the defending team
+        FreeKickAttack,  // Free kick awarded to the attacking team
+        FreeKickDefence, // Free kick awarded to the defending team
+    }
+
+    // Attacking team call at the scrum
+    #[derive(Clone, Copy, PartialEq)]
+    pub enum ScrumCall {
+        Standard,      // Hooker strikes and the ball is played from the base
+        Number8PickUp, // Number 8 picks up and carries
+    }
+
     // Scrum
-    // This is a contested forwards challange
-    // The attacking team should have some form of advantange since they have the put in
-    // ToDo: Implement a PitchPosition that will proxy for presure
-    pub fn scrum(att_team: &Team, def_team: &Team) -> (bool, RollResult) {
-        let att_chall =
-            att_team.forwards_challange_roll(&AttributeTypes::Strength) + SCRUM_PUT_IN_ADV;
-        let def_chall = def_team.forwards_challange_roll(&AttributeTypes::Strength);
-
-        // Check if the difference is greater than a critcal value
-        // Return a Critical Success otherwise
-        let res = att_chall > def_chall;
-
-        let crit = match (att_chall - def_chall).abs() > SCRUM_CRIT {
-            true => RollResult::CriticalSuccess,
-            false => RollResult::Flat,
+    // Staged contest between the two packs:
+    //  - engagement between the front rows, collapses are reset
+    //  - feed from the scrum half and strike from the hookers
+    //  - push from the tight five with support from the back row
+    // The attacking team has the put in, the defending team is under pressure near its own line
+    pub fn scrum(
+        att_team: &Team,
+        def_team: &Team,
+        pos: &PitchPosition,
+        att_is_home: &bool,
+        call: ScrumCall,
+    ) -> (ScrumResult, RollResult) {
+        // Crouch, bind, set...
+        let mut resets = 0;
+        let (att_eng, def_eng) = loop {
+            let att_eng = scrum_engagement(att_team);
+            let def_eng = scrum_engagement(def_team);
+
+            // Both front rows going to ground resets the scrum
+            if att_eng.1 == RollResult::CriticalFail && def_eng.1 == RollResult::CriticalFail {
+                resets += 1;
+                // Repeated collapses are penalised against the weaker front row
+                // A leader the referee trusts can sway the call
+                if resets > MAX_SCRUM_RESETS {
+                    let att = att_eng.0 + referee_rapport(att_team);
+                    let def = def_eng.0 + referee_rapport(def_team);
+                    return match att >= def {
+                        true => (ScrumResult::PenaltyAttack, RollResult::Flat),
+                        false => (ScrumResult::PenaltyDefence, RollResult::Flat),
+                    };
+                }
+                continue;
+            }
+            break (att_eng, def_eng);
         };
 
-        (res, crit)
+        // A single front row collapsing is penalised
+        match (att_eng.1, def_eng.1) {
+            (RollResult::CriticalFail, _) => {
+                return (ScrumResult::PenaltyDefence, RollResult::CriticalFail)
+            }
+            (_, RollResult::CriticalFail) => {
+                return (ScrumResult::PenaltyAttack, RollResult::CriticalSuccess)
+            }
+            _ => {}
+        }
+
+        // Feed, a poor put in is not straight
+        let feed = att_team
+            .active_player(Position::ScrumHalf)
+            .challange_roll(&AttributeTypes::Dexterity);
+        if feed.1 == RollResult::CriticalFail {
+            return (ScrumResult::FreeKickDefence, RollResult::CriticalFail);
+        }
+
+        // Strike, the attacking hooker has the advantage of the put in
+        let att_strike = att_team
+            .active_player(Position::Hooker)
+            .challange_roll(&AttributeTypes::Dexterity);
+        let def_strike = def_team
+            .active_player(Position::Hooker)
+            .challange_roll(&AttributeTypes::Dexterity);
+        // Defending hooker striking early
+        if def_strike.1 == RollResult::CriticalFail {
+            return (ScrumResult::FreeKickAttack, RollResult::CriticalSuccess);
+        }
+        let att_hooked = match (att_strike.1, def_strike.1) {
+            (RollResult::CriticalSuccess, _) => true,
+            (_, RollResult::CriticalSuccess) => false,
+            _ => att_strike.0 + SCRUM_PUT_IN_ADV > def_strike.0,
+        };
+
+        // Push, the engagement carries through to the shove
+        let push = (att_eng.0 - def_eng.0) / SCRUM_FRONT_ROW_WEIGHT + scrum_push(att_team)
+            - scrum_push(def_team)
+            + scrum_pressure(pos, &!att_is_home);
+
+        // A pack going backwards quickly will be penalised
+        if push > SCRUM_PENALTY_CRIT {
+            return (ScrumResult::PenaltyAttack, RollResult::CriticalSuccess);
+        } else if push < -SCRUM_PENALTY_CRIT {
+            return (ScrumResult::PenaltyDefence, RollResult::CriticalFail);
+        }
+
+        // Dominant shove for the side going forward, from the feeding side's point of view
+        let crit = match push {
+            p if p > SCRUM_CRIT => RollResult::CriticalSuccess,
+            p if p < -SCRUM_CRIT => RollResult::CriticalFail,
+            _ => RollResult::Flat,
+        };
+
+        if !att_hooked {
+            return (ScrumResult::DefenceBall, crit);
+        }
+
+        match call {
+            ScrumCall::Standard => (ScrumResult::AttackBall, crit),
+            // Number 8 needs a pack going forward to pick up cleanly
+            ScrumCall::Number8PickUp => {
+                let pick_up = att_team
+                    .active_player(Position::Number8)
+                    .challange_roll(&AttributeTypes::Dexterity);
+                match (pick_up.1, push >= 0) {
+                    (RollResult::CriticalFail, _) => {
+                        (ScrumResult::DefenceBall, RollResult::CriticalFail)
+                    }
+                    (RollResult::CriticalSuccess, _) => {
+                        (ScrumResult::Number8PickUp, RollResult::CriticalSuccess)
+                    }
+                    (_, true) => (ScrumResult::Number8PickUp, crit),
+                    (_, false) => (ScrumResult::AttackBall, crit),
+                }
+            }
+        }
+    }
+
+    // Scrum engagement
+    // Weighted strength roll for the front row, backed by the locks
+    // The heavier front row hits harder
+    fn scrum_engagement(team: &Team) -> (i32, RollResult) {
+        let front = team.get_players(&FRONT_ROW);
+        let morale = group_morale(&front);
+        let weight = pack_weight(&front, SCRUM_KG_PER_POINT);
+        let (front_row, crit) = group_roll(front, &AttributeTypes::Strength);
+        let locks = group_check(team.get_players(&SECOND_ROW), &AttributeTypes::Strength);
+        (
+            SCRUM_FRONT_ROW_WEIGHT * front_row + locks + weight + morale,
+            crit,
+        )
+    }
+
+    // Scrum push
+    // Tight five drive the scrum, the back row add half their weight
+    fn scrum_push(team: &Team) -> i32 {
+        let tight_five = team.get_players(&[&FRONT_ROW[..], &SECOND_ROW[..]].concat());
+        let back_row = team.get_players(&BACK_ROW);
+        let weight = pack_weight(&tight_five, SCRUM_KG_PER_POINT)
+            + pack_weight(&back_row, SCRUM_KG_PER_POINT) / 2;
+        group_check(tight_five, &AttributeTypes::Strength)
+            + group_check(back_row, &AttributeTypes::Strength) / 2
+            + weight
+    }
+
+    // Combined weight of a group in points
+    fn pack_weight(group: &[&Player], kg_per_point: u32) -> i32 {
+        (group.iter().map(|x| x.weight).sum::<u32>() / kg_per_point) as i32
+    }
+
+    // Pressure on the defending pack near its own line
+    fn scrum_pressure(pos: &PitchPosition, def_is_home: &bool) -> i32 {
+        if pos.in_own_5m(def_is_home) {
+            SCRUM_5M_PRESSURE
+        } else if pos.in_own_22(def_is_home) {
+            SCRUM_22_PRESSURE
+        } else {
+            0
+        }
+    }
+
+    // Target of the line out throw
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum LineOutTarget {
+        Front,
+        Middle,
+        Back,
+    }
+
+    // Line out call from the throwing team
+    #[derive(Clone, Copy)]
+    pub struct LineOutCall {
+        pub numbers: usize, // Players in the line, clamped to 2-7
+        pub target: LineOutTarget,
+        pub maul: bool, // Set up a maul from the catch
+    }
+
+    impl LineOutCall {
+        // Default full line out to the middle
+        pub fn new() -> LineOutCall {
+            LineOutCall {
+                numbers: LINE_OUT_MAX_NUMBERS,
+                target: LineOutTarget::Middle,
+                maul: false,
+            }
+        }
+    }
+
+    // Outcome of a line out
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum LineOutResult {
+        CleanCatch,  // Throwing team win the ball off the top
+        Maul,        // Throwing team win the ball and set up a maul
+        Stolen,      // Defending team win the ball
+        NotStraight, // Sanctioned throw, scrum to the defending team
     }
 
     // Line Out
     // Contested Challange Roll
-    // Challange roll for the throw, the catch and play after...
-    pub fn line_out(att_team: &Team, def_team: &Team) -> (bool, RollResult) {
+    // Challange roll for the throw, the read of the call and the contest in the air
+    pub fn line_out(
+        att_team: &Team,
+        def_team: &Team,
+        call: &LineOutCall,
+    ) -> (LineOutResult, RollResult) {
         // Throw in first check if the throw in is successful
         // Fine the hooker on the att_team
-        let hooker = att_team.get_player(Position::Hooker);
+        let hooker = att_team.active_player(Position::Hooker);
         let throw = hooker.challange_roll(&AttributeTypes::Dexterity);
 
-        match throw.1 {
+        let won = match throw.1 {
             // Perfect throw
             RollResult::CriticalSuccess => (true, RollResult::CriticalSuccess),
             // Terrible throw
-            RollResult::CriticalFail => (false, RollResult::CriticalFail),
+            RollResult::CriticalFail => {
+                return (LineOutResult::NotStraight, RollResult::CriticalFail)
+            }
             // Contestable throw
-            RollResult::Flat => (
-                contest_line_out(throw.0, att_team, def_team),
-                RollResult::Flat,
-            ),
+            RollResult::Flat => {
+                let dist = match call.target {
+                    LineOutTarget::Front => 0,
+                    LineOutTarget::Middle => LINE_OUT_MIDDLE_THROW,
+                    LineOutTarget::Back => LINE_OUT_BACK_THROW,
+                };
+                contest_line_out(throw.0 - dist, att_team, def_team, call)
+            }
+        };
+
+        match won {
+            (true, crit) if call.maul => (LineOutResult::Maul, crit),
+            (true, crit) => (LineOutResult::CleanCatch, crit),
+            (false, crit) => (LineOutResult::Stolen, crit),
         }
     }
 
+    // Line out pod
+    // Jumper targeted by the call and the lifters either side of them
+    // Jumpers come from the second row and back row, everyone else lifts
+    pub fn line_out_pod<'a>(team: &'a Team, call: &LineOutCall) -> (&'a Player, Vec<&'a Player>) {
+        let line = line_out_line(call);
+        let jumpers = line
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| SECOND_ROW.contains(x) || BACK_ROW.contains(x))
+            .map(|(i, _)| i)
+            .collect::<Vec<usize>>();
+
+        let jumper = match call.target {
+            LineOutTarget::Front => jumpers[0],
+            LineOutTarget::Middle => jumpers[jumpers.len() / 2],
+            LineOutTarget::Back => jumpers[jumpers.len() - 1],
+        };
+
+        let lifters = [jumper.checked_sub(1), Some(jumper + 1)]
+            .iter()
+            .flatten()
+            .filter(|&&i| i < line.len())
+            .map(|&i| line[i])
+            .collect::<Vec<Position>>();
+
+        (team.active_player(line[jumper]), team.get_players(&lifters))
+    }
+
+    // Positions standing in the line for the call
+    fn line_out_line(call: &LineOutCall) -> &'static [Position] {
+        &LINE_OUT_ORDER[..call
+            .numbers
+            .clamp(LINE_OUT_MIN_NUMBERS, LINE_OUT_MAX_NUMBERS)]
+    }
+
     // Contested line out
-    // Dex challange roll to see which team will recover the line out
+    // The defence first try to read the call, then the pods contest the ball in the air
     // Adding offsets to give advantage to the throwing teams
-    fn contest_line_out(put_in: i32, att_team: &Team, def_team: &Team) -> bool {
-        // Contested line out throw
-        let att_chal = att_team.forwards_challange_roll(&AttributeTypes::Dexterity);
-        let def_chal = def_team.forwards_challange_roll(&AttributeTypes::Dexterity);
+    fn contest_line_out(
+        put_in: i32,
+        att_team: &Team,
+        def_team: &Team,
+        call: &LineOutCall,
+    ) -> (bool, RollResult) {
+        // Caller disguises the call, the defending jumpers read it
+        let caller = att_team
+            .active_player(Position::SecondRow1)
+            .challange_roll(&AttributeTypes::Intelligence);
+        let reader = def_team
+            .active_player(Position::SecondRow2)
+            .challange_roll(&AttributeTypes::Wisdom);
+        let misread = match (caller.1, reader.1) {
+            (_, RollResult::CriticalSuccess) => 0,
+            (RollResult::CriticalSuccess, _) => LINE_OUT_MISREAD,
+            _ if reader.0 >= caller.0 => 0,
+            _ => LINE_OUT_MISREAD,
+        };
+
+        // The defence mirrors the numbers and the target if they read it
+        let (att_jumper, att_lifters) = line_out_pod(att_team, call);
+        let (def_jumper, def_lifters) = line_out_pod(def_team, call);
+
+        let att_jump = att_jumper.challange_roll(&AttributeTypes::Dexterity);
+        let def_jump = def_jumper.challange_roll(&AttributeTypes::Dexterity);
+
+        // Contested line out throw, taller jumpers get higher
+        let att_chal = att_jump.0
+            + group_check(att_lifters, &AttributeTypes::Strength)
+            + (att_jumper.height / LINE_OUT_CM_PER_POINT) as i32;
+        let def_chal = def_jump.0 + group_check(def_lifters, &AttributeTypes::Strength) - misread
+            + (def_jumper.height / LINE_OUT_CM_PER_POINT) as i32;
+
+        match (att_jump.1, def_jump.1) {
+            (_, RollResult::CriticalSuccess) if misread == 0 => {
+                (false, RollResult::CriticalSuccess)
+            }
+            (RollResult::CriticalSuccess, _) => (true, RollResult::CriticalSuccess),
+            (RollResult::CriticalFail, _) => (false, RollResult::CriticalFail),
+            // Add the throw score and a balancing score
+            _ => (
+                att_chal + put_in + LINE_OUT_TROW_CRIT > def_chal,
+                RollResult::Flat,
+            ),
+        }
+    }
+
+    // Type of pass
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum PassType {
+        Short,
+        Long,
+        Skip,
+        Offload, // Out of the tackle
+    }
+
+    impl PassType {
+        // Lateral distance of the pass
+        pub fn distance(&self) -> f32 {
+            match *self {
+                PassType::Short => 5.,
+                PassType::Long => 12.,
+                PassType::Skip => 18.,
+                PassType::Offload => 2.,
+            }
+        }
+
+        // Difficulty modifier for the passer
+        fn difficulty(&self) -> i32 {
+            match *self {
+                PassType::Short => 0,
+                PassType::Long => 2,
+                PassType::Skip => 4,
+                PassType::Offload => 3,
+            }
+        }
+
+        // Margin the defender must beat the pass by to intercept it
+        fn intercept_margin(&self) -> i32 {
+            match *self {
+                PassType::Short => 18,
+                PassType::Long => 15,
+                PassType::Skip => 12,
+                PassType::Offload => 16,
+            }
+        }
+    }
+
+    // Outcome of a pass
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum PassResult {
+        Clean,       // Receiver has the ball
+        ForwardPass, // Scrum to the defence
+        KnockOn,     // Receiver spills it forward, scrum to the defence
+        Intercept,   // Defender picks off the pass
+    }
+
+    // Pass
+    // Passer's Dexterity sets the quality of the pass, the receiver has to handle it
+    // The nearest defender can read a poor or long pass and intercept it
+    pub fn pass(
+        passer: &Player,
+        receiver: &Player,
+        defender: &Player,
+        pass_type: PassType,
+        pos: &mut PitchPosition,
+        att_is_home: &bool,
+    ) -> (PassResult, RollResult) {
+        let throw = passer.challange_roll(&AttributeTypes::Dexterity);
+
+        // A perfect pass goes to hand, a terrible one drifts forward
+        match throw.1 {
+            RollResult::CriticalFail => {
+                pos.advance(PASS_DEPTH, att_is_home);
+                return (PassResult::ForwardPass, RollResult::CriticalFail);
+            }
+            RollResult::CriticalSuccess => {
+                pos.move_wide(pass_type.distance());
+                pos.advance(-PASS_DEPTH, att_is_home);
+                return (PassResult::Clean, RollResult::CriticalSuccess);
+            }
+            RollResult::Flat => {}
+        }
+        let quality = throw.0 - pass_type.difficulty();
+
+        // Defender reading the pass, a poor pass can be picked off by anyone who sees it coming
+        let read = defender.challange_roll(&AttributeTypes::Wisdom);
+        let picked_off = match read.1 {
+            RollResult::CriticalSuccess => quality < PASS_HANDLING_DIFF,
+            _ => read.0 > quality + pass_type.intercept_margin(),
+        };
+        if picked_off {
+            pos.move_wide(0.5 * pass_type.distance());
+            return (PassResult::Intercept, RollResult::CriticalFail);
+        }
+
+        // Receiver handling the pass
+        pos.move_wide(pass_type.distance());
+        let catch = receiver.challange_roll(&AttributeTypes::Dexterity);
+        let dropped = match catch.1 {
+            RollResult::CriticalSuccess => false,
+            RollResult::CriticalFail => true,
+            RollResult::Flat => catch.0 + quality / 2 < PASS_HANDLING_DIFF,
+        };
+        if dropped {
+            pos.advance(PASS_DEPTH, att_is_home);
+            return (PassResult::KnockOn, catch.1);
+        }
+
+        pos.advance(-PASS_DEPTH, att_is_home);
+        (PassResult::Clean, RollResult::Flat)
+    }
+
+    // Outcome of a phase of open play
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum PhaseResult {
+        Tackled,              // Carrier held, ball recycled at the breakdown
+        DominantTackle,       // Carrier driven backwards in the tackle
+        CoverTackle,          // Line break stopped by the cover defence
+        Try,                  // Ball carrier grounds the ball in goal
+        Handling(PassResult), // Offload to a support runner goes wrong
+    }
+
+    // Everything that happened in a phase of open play
+    pub struct Phase<'a> {
+        pub result: PhaseResult,
+        pub roll: RollResult,
+        pub carriers: Vec<&'a Player>, // Ball carriers in order, the last one has the ball
+        pub tackles: Vec<(&'a Player, bool)>, // Tackle attempts and whether they were made
+        pub metres: f32,               // Metres gained by the attack
+        pub line_break: bool,
+    }
+
+    // Open play
+    // The attacking backline challenges the shape of the defensive line
+    // A broken line or tackle brings in the cover defence and support runners,
+    // otherwise the carrier is tackled by their opposite number
+    pub fn open_play<'a>(
+        att_team: &'a Team,
+        def_team: &'a Team,
+        carrier: &'a Player,
+        pos: &mut PitchPosition,
+        att_is_home: &bool,
+    ) -> Phase<'a> {
+        let start = pos.own_line_dist(att_is_home);
+        let mut phase = Phase {
+            result: PhaseResult::Tackled,
+            roll: RollResult::Flat,
+            carriers: [carrier].to_vec(),
+            tackles: [].to_vec(),
+            metres: 0.,
+            line_break: false,
+        };
+
+        // Attacking shape against the defensive line
+        // Tactics stretch or tighten the line, extra bodies in the rucks leave it short
+        let att_backs = att_team.get_players(&BACKS);
+        let att_shape = group_morale(&att_backs)
+            + group_check(att_backs, &AttributeTypes::Intelligence)
+            + att_team.tactics.width.shape_mod()
+            + att_team.tactics.ruck_shape_mod();
+        let def_line = def_team.get_players(&DEFENSIVE_LINE);
+        let def_shape = group_morale(&def_line)
+            + group_check(def_line, &AttributeTypes::Intelligence)
+            + def_team.tactics.line_speed.shape_mod()
+            + def_team.tactics.contest_shape_mod();
+        let shape = att_shape - def_shape;
+
+        if shape > LINE_BREAK_CRIT {
+            phase.line_break = true;
+        } else {
+            // Carrier takes on their opposite number
+            // A missed tackle leaves it to the next defenders in the line
+            let opposite = def_team.active_player(carrier.selected_position);
+            let tacklers = [opposite]
+                .into_iter()
+                .chain(
+                    def_team
+                        .get_players(&DEFENSIVE_LINE)
+                        .into_iter()
+                        .filter(|x| !std::ptr::eq(*x, opposite)),
+                )
+                .take(def_team.tactics.line_speed.defenders_in_contact());
+            let mut metres = (CARRY_METRES + shape / CARRY_METRE_DIV).clamp(0, CARRY_MAX_METRES);
+
+            phase.line_break = true;
+            for tackler in tacklers {
+                let (tackled, roll) = tackle(carrier, tackler);
+                // A carrier spilling it has not beaten the tackler
+                phase
+                    .tackles
+                    .push((tackler, tackled || roll == RollResult::CriticalFail));
+                phase.roll = roll;
+
+                match (tackled, roll) {
+                    // Dominant tackle
+                    (true, RollResult::CriticalSuccess) => {
+                        metres = 0;
+                        pos.advance(-DOMINANT_TACKLE_METRES, att_is_home);
+                        phase.result = PhaseResult::DominantTackle;
+                    }
+                    (true, _) => {}
+                    // Broken tackle, straight through the line
+                    (false, RollResult::CriticalSuccess) => break,
+                    // Carrier spills it in contact, no gain
+                    (false, RollResult::CriticalFail) => {
+                        metres = 0;
+                        phase.result = PhaseResult::Handling(PassResult::KnockOn);
+                    }
+                    // Missed tackle, carrier gets over the gain line
+                    (false, _) => {
+                        metres += CARRY_MAX_METRES;
+                        continue;
+                    }
+                }
+                phase.line_break = false;
+                break;
+            }
+
+            if !phase.line_break {
+                pos.advance(metres as f32, att_is_home);
+                // Close enough to reach over the line
+                if pos.over_try_line(att_is_home) {
+                    phase.result = PhaseResult::Try;
+                }
+            }
+        }
+
+        if phase.line_break {
+            cover_defence(att_team, def_team, &mut phase, pos, att_is_home);
+        }
+
+        phase.metres = pos.own_line_dist(att_is_home) - start;
+        phase
+    }
+
+    // Cover defence
+    // The full back and wingers try to chase down a line break
+    // A carrier caught by the cover looks for a support runner to offload to
+    fn cover_defence<'a>(
+        att_team: &'a Team,
+        def_team: &'a Team,
+        phase: &mut Phase<'a>,
+        pos: &mut PitchPosition,
+        att_is_home: &bool,
+    ) {
+        for cover in def_team.get_players(&COVER_DEFENCE) {
+            pos.advance(LINE_BREAK_METRES, att_is_home);
+            if pos.over_try_line(att_is_home) {
+                break;
+            }
+
+            let carrier = phase.carriers[phase.carriers.len() - 1];
+            let (tackled, roll) = tackle(carrier, cover);
+            phase
+                .tackles
+                .push((cover, tackled || roll == RollResult::CriticalFail));
+            phase.roll = roll;
+            match (tackled, roll) {
+                // Carrier spills it with the cover closing in
+                (false, RollResult::CriticalFail) => {
+                    phase.result = PhaseResult::Handling(PassResult::KnockOn);
+                    return;
+                }
+                (false, _) => continue,
+                (true, _) => {}
+            }
+
+            // Look for someone on the shoulder
+            let support = att_team
+                .get_players(&SUPPORT_RUNNERS)
+                .into_iter()
+                .filter(|x| !phase.carriers.iter().any(|c| std::ptr::eq(*c, *x)))
+                .find(|x| x.challange_roll(&AttributeTypes::Dexterity).0 > SUPPORT_RUNNER_DIFF);
+            let Some(support) = support else {
+                phase.result = PhaseResult::CoverTackle;
+                return;
+            };
+
+            let (res, roll) = pass(carrier, support, cover, PassType::Offload, pos, att_is_home);
+            phase.roll = roll;
+            if res != PassResult::Clean {
+                phase.result = PhaseResult::Handling(res);
+                return;
+            }
+            phase.carriers.push(support);
+        }
+
+        // Cover beaten, run in under the posts
+        pos.advance(pos.opp_line_dist(att_is_home), att_is_home);
+        phase.result = PhaseResult::Try;
+    }
+
+    // Outcome of the breakdown after a tackle
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum BreakdownResult {
+        Retained,       // Attacking team recycle the ball
+        Turnover,       // Defending team win the ball
+        PenaltyAttack,  // Defence infringe, hands in the ruck or offside
+        PenaltyDefence, // Attack infringe, holding on or sealing off
+    }
+
+    // Breakdown
+    // Carrier presents the ball with support from the attacking forwards
+    // The defending openside competes for the ball with their own support
+    pub fn breakdown(
+        att_team: &Team,
+        def_team: &Team,
+        carrier: &Player,
+        att_numbers: usize,
+        def_numbers: usize,
+    ) -> (BreakdownResult, RollResult) {
+        let jackal = def_team.active_player(Position::OpenSideFlanker);
+
+        let present = carrier.challange_roll(&AttributeTypes::Strength);
+        let compete = jackal.challange_roll(&AttributeTypes::Strength);
+
+        // Infringements at the ruck
+        // The referee may let the carrier off with a word to their leader
+        match (present.1, compete.1) {
+            (RollResult::CriticalFail, _) if referee_rapport(att_team) >= REFEREE_BENEFIT => {
+                return (BreakdownResult::Retained, RollResult::Flat)
+            }
+            (RollResult::CriticalFail, _) => {
+                return (BreakdownResult::PenaltyDefence, RollResult::CriticalFail)
+            }
+            (_, RollResult::CriticalFail) => {
+                return (BreakdownResult::PenaltyAttack, RollResult::CriticalSuccess)
+            }
+            (_, RollResult::CriticalSuccess) => {
+                return (BreakdownResult::Turnover, RollResult::CriticalFail)
+            }
+            _ => {}
+        }
+
+        // Support arriving at the ruck
+        let att_support = ruck_support(att_team, carrier, att_numbers);
+        let def_support = ruck_support(def_team, jackal, def_numbers);
+        let res = present.0 + group_check(att_support, &AttributeTypes::Strength)
+            - compete.0
+            - group_check(def_support, &AttributeTypes::Strength);
+
+        if res < -BREAKDOWN_TURNOVER_CRIT {
+            (BreakdownResult::Turnover, RollResult::Flat)
+        } else if res > BREAKDOWN_QUICK_BALL {
+            (BreakdownResult::Retained, RollResult::CriticalSuccess)
+        } else {
+            (BreakdownResult::Retained, RollResult::Flat)
+        }
+    }
+
+    // Captain's Charisma check with the referee
+    pub fn referee_rapport(team: &Team) -> i32 {
+        team.captain().challange_roll(&AttributeTypes::Charisma).0
+    }
 
-        // Add the throw score and a balancing score
-        att_chal + put_in + LINE_OUT_TROW_CRIT > def_chal
+    // Forwards joining a ruck alongside the player already there
+    fn ruck_support<'a>(team: &'a Team, first: &Player, numbers: usize) -> Vec<&'a Player> {
+        team.get_players(&FORWARDS)
+            .into_iter()
+            .filter(|x| !std::ptr::eq(*x, first))
+            .take(numbers)
+            .collect()
     }
 
     // Generic group check
+    // Supporting rolls only, the contest adds the team's morale once
     fn group_check(group: Vec<&Player>, attr: &AttributeTypes) -> i32 {
-        group.iter().map(|x| x.challange_roll(attr).0).sum()
+        group.iter().map(|x| x.support_roll(attr).0).sum()
+    }
+
+    // Morale and momentum of the team a group plays for
+    fn group_morale(group: &[&Player]) -> i32 {
+        group.first().map(|x| x.morale).unwrap_or(0)
+    }
+
+    // Generic group roll
+    // Sum of the group with the net critical result
+    // More natural 1s than 20s is a critical fail and vice versa
+    fn group_roll(group: Vec<&Player>, attr: &AttributeTypes) -> (i32, RollResult) {
+        let rolls = group
+            .iter()
+            .map(|x| x.support_roll(attr))
+            .collect::<Vec<(i32, RollResult)>>();
+        let count = |res: RollResult| rolls.iter().filter(|x| x.1 == res).count();
+        let net =
+            count(RollResult::CriticalSuccess) as i32 - count(RollResult::CriticalFail) as i32;
+
+        let crit = match net {
+            n if n > 0 => RollResult::CriticalSuccess,
+            n if n < 0 => RollResult::CriticalFail,
+            _ => RollResult::Flat,
+        };
+        (rolls.iter().map(|x| x.0).sum(), crit)
     }
 
     // Generic group contest
@@ -134,25 +919,98 @@ pub mod events {
     //     att_check - def_check
     // }
 
+    // Outcome of a maul
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum MaulResult {
+        Try,        // Maul driven over the try line
+        BallOut,    // Attacking team use the ball from the back
+        Turnover,   // Maul stopped, use it or lose it, scrum to the defence
+        Penalty,    // Maul illegally collapsed by the defence
+        PenaltyTry, // Maul illegally collapsed with a try likely
+    }
+
     // Maul
     // Contested strength test between two groups of players
-    pub fn maul(att_group: Vec<&Player>, def_group: Vec<&Player>) -> (bool, RollResult) {
-        let res = group_check(att_group, &AttributeTypes::Strength)
-            - group_check(def_group, &AttributeTypes::Strength);
-
-        // Did the maul event succeed?
-        // On draw (res == 0) attacher maintain the advantage
-        let suc = res >= 0;
-
-        let roll = if res.abs() < MAUL_CRIT {
-            RollResult::Flat
-        } else if res > 0 {
-            RollResult::CriticalSuccess
-        } else {
-            RollResult::CriticalFail
-        };
+    // The maul drives forward each round on the strength and weight differential
+    // Mauls that stall twice are called to use it or lose it
+    pub fn maul(
+        att_group: Vec<&Player>,
+        def_group: Vec<&Player>,
+        pos: &mut PitchPosition,
+        att_is_home: &bool,
+    ) -> (MaulResult, RollResult) {
+        let weight =
+            pack_weight(&att_group, MAUL_KG_PER_POINT) - pack_weight(&def_group, MAUL_KG_PER_POINT);
+        let morale = group_morale(&att_group) - group_morale(&def_group);
+        let mut stalls = 0;
+        for _ in 0..MAUL_MAX_ROUNDS {
+            let att = group_check(att_group.clone(), &AttributeTypes::Strength);
+            let def = group_check(def_group.clone(), &AttributeTypes::Strength);
+
+            let res = att - def + weight + morale + MAUL_DRIVE_ADV;
+
+            // Defence going backwards quickly pulling the maul down illegally
+            if res > MAUL_COLLAPSE_MARGIN {
+                return match pos.opp_line_dist(att_is_home) <= MAUL_PENALTY_TRY_DIST {
+                    true => (MaulResult::PenaltyTry, RollResult::CriticalSuccess),
+                    false => (MaulResult::Penalty, RollResult::CriticalSuccess),
+                };
+            }
+
+            // Held up dead by the defence
+            if res < -MAUL_HELD_UP_MARGIN {
+                return (MaulResult::Turnover, RollResult::CriticalFail);
+            }
 
-        (suc, roll)
+            // Maul has stopped moving
+            if res <= 0 {
+                stalls += 1;
+                if stalls >= MAUL_MAX_STALLS {
+                    return (MaulResult::Turnover, RollResult::Flat);
+                }
+                continue;
+            }
+
+            // Drive on
+            let metres = (res / MAUL_METRE_DIV).clamp(1, MAUL_MAX_METRES);
+            pos.advance(metres as f32, att_is_home);
+            if pos.over_try_line(att_is_home) {
+                let roll = match res > MAUL_CRIT {
+                    true => RollResult::CriticalSuccess,
+                    false => RollResult::Flat,
+                };
+                return (MaulResult::Try, roll);
+            }
+        }
+
+        (MaulResult::BallOut, RollResult::Flat)
+    }
+
+    // Players in the line out, these form the maul from the catch along with the hooker
+    pub fn line_out_maul_group<'a>(team: &'a Team, call: &LineOutCall) -> Vec<&'a Player> {
+        let mut group = team.get_players(line_out_line(call));
+        // Whoever is covering a sin binned hooker may already be in the line
+        let hooker = team.active_player(Position::Hooker);
+        if !group.iter().any(|x| std::ptr::eq(*x, hooker)) {
+            group.push(hooker);
+        }
+        group
+    }
+
+    // Ball carrier and the nearest forwards joining them to form a maul
+    pub fn carry_maul_group<'a>(
+        team: &'a Team,
+        carrier: &'a Player,
+        numbers: usize,
+    ) -> Vec<&'a Player> {
+        let mut group = [carrier].to_vec();
+        group.extend(
+            team.get_players(&FORWARDS)
+                .into_iter()
+                .filter(|x| !std::ptr::eq(*x, carrier))
+                .take(numbers),
+        );
+        group
     }
 
     // Penalty kick to goal
@@ -180,11 +1038,55 @@ pub mod events {
         // Check the setup
         let setup = group_check(players, &AttributeTypes::Intelligence);
 
-        // Give advantage on the kick
+        // Reward a well protected kicker
         // ToDo: Modify challange roll to accept advantage/disadvantage
-        if setup > DROP_KICK_SETUP_MOD {
-            return false
+        let bonus = match setup > KICKER_PROTECTION {
+            true => DROP_KICK_SETUP_MOD,
+            false => 0,
+        };
+
+        let diff = pos.goal_kick_difficutly(is_home);
+        let res = kicker.challange_roll(&AttributeTypes::Dexterity);
+        match res.1 {
+            RollResult::CriticalSuccess => true,
+            RollResult::CriticalFail => false,
+            RollResult::Flat => res.0 + bonus > diff,
+        }
+    }
+
+    // Kick to touch
+    // Uncontested kick for territory, a good kicker finds touch further downfield
+    // Reading the space behind the defence with Wisdom adds or costs metres
+    // Return whether the ball found touch
+    pub fn kick_to_touch(
+        kicker: &Player,
+        pos: &mut PitchPosition,
+        is_home: &bool,
+    ) -> (bool, RollResult) {
+        let res = kicker.challange_roll(&AttributeTypes::Dexterity);
+        match res.1 {
+            // Kick stays in play
+            RollResult::CriticalFail => {
+                pos.advance(TOUCH_KICK_MISS_METRES, is_home);
+                (false, RollResult::CriticalFail)
+            }
+            RollResult::CriticalSuccess => {
+                find_touch(pos, TOUCH_KICK_PERFECT_METRES, is_home);
+                (true, RollResult::CriticalSuccess)
+            }
+            RollResult::Flat => {
+                let read = kicker.challange_roll(&AttributeTypes::Wisdom).0;
+                let metres = (res.0 + read - TOUCH_KICK_AVERAGE_READ).max(0) * TOUCH_KICK_METRES;
+                find_touch(pos, metres as f32, is_home);
+                (true, RollResult::Flat)
+            }
         }
-        true
+    }
+
+    // Ball finds touch downfield, the line out is formed no closer than the opposition 5m
+    fn find_touch(pos: &mut PitchPosition, metres: f32, is_home: &bool) {
+        let metres = metres.min(pos.opp_line_dist(is_home) - TOUCH_LINE_OUT_DIST);
+        pos.advance(metres, is_home);
+        pos.to_touch();
     }
 }
@@ -0,0 +1,129 @@
+// Module to keep the books of a club
+
+pub mod finance {
+    use crate::team::team::Team;
+
+    // Gate takings from a home match, in thousands
+    const GATE_BASE: u32 = 80;
+    // Winning teams draw bigger crowds, per league point so far
+    const GATE_PER_POINT: u32 = 2;
+    // Sponsorship for the league winner, each place below earns less
+    const SPONSORSHIP_TOP: u32 = 800;
+    const SPONSORSHIP_STEP: u32 = 80;
+    const SPONSORSHIP_MIN: u32 = 200;
+    // Income a club can count on each season, to plan its wages
+    const EXPECTED_INCOME: u32 = 1000;
+    // Share of the transfer funds a club will put into wages for a season
+    const BUDGET_WAGE_SHARE: i64 = 4;
+
+    // Income and spending of a club over a season, in thousands
+    #[derive(Clone, Copy, Default)]
+    pub struct Accounts {
+        pub gate: u32,
+        pub sponsorship: u32,
+        pub wages: u32,
+    }
+
+    impl Accounts {
+        pub fn new() -> Accounts {
+            Accounts::default()
+        }
+
+        pub fn profit(&self) -> i64 {
+            self.gate as i64 + self.sponsorship as i64 - self.wages as i64
+        }
+    }
+
+    // Salary cap for a competition
+    // Clubs whose wage bill is over the limit at the start of a season lose points
+    #[derive(Clone, Copy)]
+    pub struct SalaryCap {
+        pub limit: u32,
+        pub deduction: u32, // Points lost for a breach, and for every tenth of the limit over it
+    }
+
+    impl SalaryCap {
+        pub fn new(limit: u32, deduction: u32) -> SalaryCap {
+            SalaryCap { limit, deduction }
+        }
+
+        // Points deducted for a wage bill
+        pub fn penalty(&self, wages: u32) -> u32 {
+            match wages.checked_sub(self.limit) {
+                None | Some(0) => 0,
+                Some(over) => self.deduction * (1 + over * 10 / self.limit.max(1)),
+            }
+        }
+    }
+
+    // Salaries of the whole squad for a season
+    pub fn wage_bill(team: &Team) -> u32 {
+        team.players.iter().map(|x| x.contract.salary).sum()
+    }
+
+    // Most a club can spend on wages in a season
+    pub fn wage_budget(team: &Team) -> u32 {
+        (EXPECTED_INCOME as i64 + team.budget / BUDGET_WAGE_SHARE).max(0) as u32
+    }
+
+    // Whether a club can pay a salary in place of an outgoing one
+    pub fn affordable(team: &Team, salary: u32, outgoing: u32) -> bool {
+        (wage_bill(team) + salary).saturating_sub(outgoing) <= wage_budget(team)
+    }
+
+    // Takings from a home match, for a team with this many league points
+    pub fn gate_revenue(points: u32) -> u32 {
+        GATE_BASE + points * GATE_PER_POINT
+    }
+
+    // Sponsorship for a final league position, starting from one
+    pub fn sponsorship(position: usize) -> u32 {
+        let step = SPONSORSHIP_STEP.saturating_mul(position.saturating_sub(1) as u32);
+        SPONSORSHIP_TOP.saturating_sub(step).max(SPONSORSHIP_MIN)
+    }
+
+    // Take the gate from a home match
+    pub fn take_gate(team: &mut Team, points: u32) {
+        let gate = gate_revenue(points);
+        team.accounts.gate += gate;
+        team.budget += gate as i64;
+    }
+
+    // Pay the sponsorship and wages at the end of a season
+    // Returns the season's accounts and starts new ones
+    pub fn close_season(team: &mut Team, position: usize) -> Accounts {
+        team.accounts.sponsorship = sponsorship(position);
+        team.accounts.wages = wage_bill(team);
+        team.budget += team.accounts.sponsorship as i64 - team.accounts.wages as i64;
+        std::mem::replace(&mut team.accounts, Accounts::new())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn no_penalty_under_or_at_the_cap() {
+            let cap = SalaryCap::new(1000, 2);
+            assert_eq!(cap.penalty(0), 0);
+            assert_eq!(cap.penalty(999), 0);
+            assert_eq!(cap.penalty(1000), 0);
+        }
+
+        #[test]
+        fn penalty_grows_every_tenth_over_the_cap() {
+            let cap = SalaryCap::new(1000, 2);
+            assert_eq!(cap.penalty(1001), 2);
+            assert_eq!(cap.penalty(1099), 2);
+            assert_eq!(cap.penalty(1100), 4);
+            assert_eq!(cap.penalty(1250), 6);
+        }
+
+        #[test]
+        fn zero_cap_penalises_any_wages() {
+            let cap = SalaryCap::new(0, 2);
+            assert_eq!(cap.penalty(0), 0);
+            assert!(cap.penalty(1) > 0);
+        }
+    }
+}
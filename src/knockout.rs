@@ -0,0 +1,319 @@
+// Module to run single elimination brackets
+
+pub mod knockout {
+    use std::fmt::Write;
+
+    use crate::{
+        competition::competition::Competition,
+        match_engine::match_engine::{Match, Side},
+        report::report::MatchReport,
+        team::team::{pair_mut, Team},
+    };
+
+    // How a tie was settled
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Decider {
+        FullTime,
+        ExtraTime,
+        SuddenDeath,
+        KickingCompetition,
+    }
+
+    // Result of a played tie
+    #[derive(Clone, Copy)]
+    pub struct TieResult {
+        pub home_score: i32,
+        pub away_score: i32,
+        pub decided_by: Decider,
+        pub winner: Side,
+    }
+
+    // Tie between two teams, by index into the teams
+    // A team without an opponent has a bye into the next round
+    pub struct Tie {
+        pub round: usize,
+        pub home: usize,
+        pub away: Option<usize>,
+        pub result: Option<TieResult>,
+    }
+
+    impl Tie {
+        // Team going through to the next round
+        pub fn winner(&self) -> Option<usize> {
+            match (self.away, self.result) {
+                (None, _) => Some(self.home),
+                (Some(away), Some(res)) => match res.winner {
+                    Side::Home => Some(self.home),
+                    Side::Away => Some(away),
+                },
+                (Some(_), None) => None,
+            }
+        }
+    }
+
+    // Knockout
+    // Single elimination bracket, the higher seed is at home in every tie
+    pub struct Knockout {
+        pub name: String,
+        pub seeds: Vec<usize>, // Team indices, top seed first
+        pub ties: Vec<Tie>,
+    }
+
+    impl Knockout {
+        // Bracket from teams in seed order
+        // Top seeds get byes when the number of teams is not a power of two
+        pub fn new(name: &str, seeds: Vec<usize>) -> Knockout {
+            let size = seeds.len().next_power_of_two();
+            let slots = bracket_order(size);
+
+            let mut knockout = Knockout {
+                name: name.to_string(),
+                seeds,
+                ties: Vec::new(),
+            };
+            for pair in slots.chunks(2) {
+                let teams = pair
+                    .iter()
+                    .filter_map(|&x| knockout.seeds.get(x).copied())
+                    .collect::<Vec<usize>>();
+                if let Some(tie) = knockout.tie(0, &teams) {
+                    knockout.ties.push(tie);
+                }
+            }
+            knockout
+        }
+
+        // Playoffs for the top of a league table
+        pub fn playoffs(name: &str, league: &Competition, qualifiers: usize) -> Knockout {
+            let seeds = league
+                .table()
+                .iter()
+                .take(qualifiers)
+                .map(|x| x.team)
+                .collect();
+            Knockout::new(name, seeds)
+        }
+
+        // Seed of a team, zero is the top seed
+        pub fn seed(&self, team: usize) -> usize {
+            self.seeds
+                .iter()
+                .position(|&x| x == team)
+                .unwrap_or(usize::MAX)
+        }
+
+        // Latest round drawn so far
+        pub fn round(&self) -> usize {
+            self.ties.iter().map(|x| x.round).max().unwrap_or(0)
+        }
+
+        // Winner of the final
+        pub fn champion(&self) -> Option<usize> {
+            let round = self.round();
+            let last = self
+                .ties
+                .iter()
+                .filter(|x| x.round == round)
+                .collect::<Vec<&Tie>>();
+            match last.len() {
+                1 => last[0].winner(),
+                _ => None,
+            }
+        }
+
+        // Play the latest round and draw the next one
+        pub fn play_round(&mut self, teams: &mut [Team]) {
+            let round = self.round();
+            for tie in self.ties.iter_mut() {
+                if let (true, Some(away), None) = (tie.round == round, tie.away, tie.result) {
+                    let (home, away) = pair_mut(teams, tie.home, away);
+                    tie.result = Some(play_tie(home, away));
+                }
+            }
+
+            // Winners of neighbouring ties meet in the next round
+            let winners = self
+                .ties
+                .iter()
+                .filter(|x| x.round == round)
+                .filter_map(|x| x.winner())
+                .collect::<Vec<usize>>();
+            if winners.len() > 1 {
+                for pair in winners.chunks(2) {
+                    if let Some(tie) = self.tie(round + 1, pair) {
+                        self.ties.push(tie);
+                    }
+                }
+            }
+        }
+
+        // Play every round until there is a champion
+        pub fn play_all(&mut self, teams: &mut [Team]) {
+            while self.champion().is_none() && !self.ties.is_empty() {
+                self.play_round(teams);
+            }
+        }
+
+        // Bracket as plain text
+        pub fn bracket_text(&self, teams: &[Team]) -> String {
+            let mut out = String::new();
+            let _ = writeln!(out, "{}", self.name);
+            for round in 0..=self.round() {
+                let _ = writeln!(out, "{}", self.round_name(round));
+                for tie in self.ties.iter().filter(|x| x.round == round) {
+                    let home = &teams[tie.home].name;
+                    let _ = match (tie.away, tie.result) {
+                        (None, _) => writeln!(out, "  {} bye", home),
+                        (Some(away), None) => writeln!(out, "  {} v {}", home, teams[away].name),
+                        (Some(away), Some(res)) => writeln!(
+                            out,
+                            "  {} {} - {} {}{}",
+                            home,
+                            res.home_score,
+                            res.away_score,
+                            teams[away].name,
+                            match res.decided_by {
+                                Decider::FullTime => "",
+                                Decider::ExtraTime => " (aet)",
+                                Decider::SuddenDeath => " (sudden death)",
+                                Decider::KickingCompetition => " (kicking competition)",
+                            }
+                        ),
+                    };
+                }
+            }
+            if let Some(champion) = self.champion() {
+                let _ = writeln!(out, "Winner: {}", teams[champion].name);
+            }
+            out
+        }
+
+        // Name of a round from the number of ties in it
+        pub fn round_name(&self, round: usize) -> String {
+            let remaining = (self.seeds.len().next_power_of_two() >> round).max(1);
+            match remaining {
+                2 => "Final".to_string(),
+                4 => "Semi finals".to_string(),
+                8 => "Quarter finals".to_string(),
+                n => format!("Round of {}", n),
+            }
+        }
+
+        // Tie between up to two teams, higher seed at home
+        fn tie(&self, round: usize, teams: &[usize]) -> Option<Tie> {
+            let (home, away) = match *teams {
+                [a, b] if self.seed(b) < self.seed(a) => (b, Some(a)),
+                [a, b] => (a, Some(b)),
+                [a] => (a, None),
+                _ => return None,
+            };
+            Some(Tie {
+                round,
+                home,
+                away,
+                result: None,
+            })
+        }
+    }
+
+    // Seed for each slot of the bracket, so the top seeds meet as late as possible
+    // For eight teams this is 1 v 8, 4 v 5, 2 v 7, 3 v 6
+    fn bracket_order(size: usize) -> Vec<usize> {
+        let mut order = vec![0];
+        while order.len() < size {
+            let n = order.len() * 2;
+            order = order.iter().flat_map(|&x| [x, n - 1 - x]).collect();
+        }
+        order
+    }
+
+    // Play a tie to a finish, count the games played and name the same lineups for the next round
+    fn play_tie(home: &mut Team, away: &mut Team) -> TieResult {
+        let lineups = (home.lineup(), away.lineup());
+        let starters = (home.on_field(), away.on_field());
+
+        let mut game = Match::new(home, away);
+        game.play();
+        let mut decided_by = Decider::FullTime;
+        if game.leader().is_none() {
+            decided_by = Decider::ExtraTime;
+            game.extra_time();
+        }
+        if game.leader().is_none() {
+            decided_by = Decider::SuddenDeath;
+            game.sudden_death();
+        }
+        let winner = match game.leader() {
+            Some(side) => side,
+            None => {
+                decided_by = Decider::KickingCompetition;
+                game.kicking_competition()
+            }
+        };
+        let report = MatchReport::from_match(&game);
+        let result = TieResult {
+            home_score: game.home.score,
+            away_score: game.away.score,
+            decided_by,
+            winner,
+        };
+
+        home.add_game(&starters.0);
+        away.add_game(&starters.1);
+        home.add_ratings(&report.home_ratings);
+        away.add_ratings(&report.away_ratings);
+        home.restore_lineup(&lineups.0);
+        away.restore_lineup(&lineups.1);
+        result
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::match_engine::match_engine::HOME_MORALE;
+
+        #[test]
+        fn every_seed_has_one_slot() {
+            for size in [2, 4, 8, 16] {
+                let mut order = bracket_order(size);
+                order.sort();
+                assert_eq!(order, (0..size).collect::<Vec<usize>>());
+            }
+        }
+
+        #[test]
+        fn top_two_seeds_only_meet_in_the_final() {
+            for size in [4, 8] {
+                let order = bracket_order(size);
+                let (top, bottom) = order.split_at(size / 2);
+                assert!(top.contains(&0), "size {}", size);
+                assert!(bottom.contains(&1), "size {}", size);
+            }
+        }
+
+        #[test]
+        fn higher_seed_plays_at_home_with_the_crowd_behind_them() {
+            let mut teams = (0..4).map(|_| Team::new()).collect::<Vec<Team>>();
+            let knockout = Knockout::new("Cup", vec![2, 0, 3, 1]);
+            for tie in knockout.ties.iter() {
+                let away = tie.away.unwrap();
+                assert!(knockout.seed(tie.home) < knockout.seed(away));
+
+                let (home, away) = pair_mut(&mut teams, tie.home, away);
+                home.set_morale(0);
+                away.set_morale(0);
+                let morale = (home.morale, away.morale);
+                let game = Match::new(home, away);
+                assert_eq!(game.home.morale, morale.0 + HOME_MORALE);
+                assert_eq!(game.away.morale, morale.1);
+            }
+        }
+
+        #[test]
+        fn top_seed_plays_the_bottom_seed() {
+            for size in [4, 8] {
+                assert_eq!(bracket_order(size)[..2], [0, size - 1]);
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+// Each module wraps its contents in an inner module of the same name
+#![allow(clippy::module_inception)]
+// Types are built with `new()` rather than `Default`
+#![allow(clippy::new_without_default)]
+
+pub mod academy;
+pub mod career;
+pub mod coach;
+pub mod com;
+pub mod commentary;
+pub mod competition;
+pub mod events;
+pub mod finance;
+pub mod knockout;
+pub mod match_engine;
+pub mod pitch;
+pub mod player;
+pub mod random_engine;
+pub mod report;
+pub mod stats;
+pub mod tactics;
+pub mod team;
+pub mod training;
+pub mod transfer;
@@ -1,45 +1,61 @@
 // use rand::Rng;
-mod com;
-mod events;
-mod pitch;
-mod player;
-mod random_engine;
-mod team;
-
-use random_engine::rng_eng::{
+use rugby_game::random_engine::rnd_name::{NameGenerator, Nationality};
+use rugby_game::random_engine::rng_eng::{
     // roll,
     // RollType,
     AttributeTypes,
     Attributes,
+    ATTRIBUTE_MAX,
 };
 
-use player::player::{
+use rugby_game::player::player::{
     get_position,
     // FORWARDS,
     Player,
     Position,
 };
 
-use team::team::Team;
+use rugby_game::team::team::Team;
+use rugby_game::training::training::TrainingProgress;
+use rugby_game::transfer::transfer::{Ambitious, Contract};
 
-use events::events::{line_out, scrum, tackle};
+use rugby_game::career::career::Career;
+use rugby_game::coach::coach::Aggressive;
+use rugby_game::commentary::commentary::{Classic, Commentary};
+use rugby_game::competition::competition::Competition;
+use rugby_game::finance::finance::SalaryCap;
+use rugby_game::knockout::knockout::Knockout;
+use rugby_game::match_engine::match_engine::Match;
+use rugby_game::report::report::MatchReport;
+use rugby_game::tactics::tactics::{AttackWidth, GamePlan};
 
 fn main() {
     let mut _new_player = Player {
         age: 24,
         name: "Joey Gilroy".to_string(),
+        nationality: Nationality::Irish,
         position: [
             Position::BlindSideFlanker,
             Position::OpenSideFlanker,
             Position::Number8,
         ]
         .to_vec(),
-        weight: 180,
+        height: 191,
+        weight: 108,
         attributes: Attributes::new(),
         has_advantage: [AttributeTypes::Strength].to_vec(),
         has_disadvantage: [].to_vec(),
         is_selected: true,
         selected_position: Position::Sub,
+        potential: ATTRIBUTE_MAX,
+        games: 0,
+        injury_weeks: 0,
+        training: TrainingProgress::new(),
+        contract: Contract::new(),
+        ratings: [].to_vec(),
+        fatigue: 0.,
+        sin_bin: 0,
+        morale: 0,
     };
 
     let mut new_team = Team::new();
@@ -52,11 +68,16 @@ fn main() {
         temp_player.position.push(get_position(i));
         new_team.players.push(temp_player);
 
+        let player = &new_team.players[usize::from(i - 1)];
         println!(
-            "{}, {}: {:?}",
+            "{}, {} ({}): {:?}, {}cm {}kg {:?}",
             i,
-            new_team.players[usize::from(i - 1)].name,
-            new_team.players[usize::from(i - 1)].position[0]
+            player.name,
+            player.nationality.name(),
+            player.position[0],
+            player.height,
+            player.weight,
+            player.body_type()
         );
     }
 
@@ -73,15 +94,125 @@ fn main() {
         new_team.backs_challange_roll(&AttributeTypes::Strength)
     );
 
-    let (res, _ty) = tackle(&new_team.players[0], &new_team.players[2]);
+    // Extra name packs can be loaded at runtime, see RUGBY_NAME_PACKS
+    let mut names = NameGenerator::from_env();
+
+    let mut away_team = Team::new();
+    away_team.name = "Dundrum Dragons".to_string();
+    away_team.coach = Box::new(Aggressive);
+    away_team.set_nationalities(
+        vec![
+            (Nationality::Irish, 50),
+            (Nationality::SouthAfrican, 30),
+            (Nationality::NewZealander, 20),
+            (Nationality::French, 10),
+        ],
+        &mut names,
+    );
+    away_team.tactics.game_plan = GamePlan::Running;
+    away_team.tactics.width = AttackWidth::Wide;
     println!(
-        "{} is tackled by {}...\n{}",
-        new_team.players[0].name, new_team.players[2].name, res
+        "Captains: {} and {}",
+        new_team.captain().name,
+        away_team.captain().name
     );
+    let mut commentary = Commentary::new(Box::new(Classic));
+    let mut game = Match::new(&mut new_team, &mut away_team);
+    game.observers.push(&mut commentary);
+    game.play();
 
-    let (res_scrum, res_crit) = scrum(&new_team, &new_team);
-    println!("{} -> {:?}", res_scrum, res_crit);
+    let report = MatchReport::from_match(&game);
+    println!("\n{}", report.to_text());
 
-    let (res_scrum, res_crit) = line_out(&new_team, &new_team);
-    println!("{} -> {:?}", res_scrum, res_crit);
+    let teams = [
+        "Churchtown Firehawks",
+        "Dundrum Dragons",
+        "Rathmines Ravens",
+        "Sandymount Sharks",
+        "Howth Harriers",
+        "Malahide Mariners",
+    ]
+    .iter()
+    .map(|name| {
+        let mut team = Team::new();
+        team.name = name.to_string();
+        team
+    })
+    .collect();
+    let mut league = Competition::new("Dublin League", teams);
+    league.play_all();
+    println!("{}", league.table_text());
+
+    let mut playoffs = Knockout::playoffs("Dublin League Playoffs", &league, 4);
+    playoffs.play_all(&mut league.teams);
+    println!("{}", playoffs.bracket_text(&league.teams));
+
+    let mut career = Career::new(league, 0, 2026);
+    career.league.teams[1].manager = Box::new(Ambitious);
+    career.league.salary_cap = Some(SalaryCap::new(1200, 2));
+    let kicker = &mut career.league.teams[0].players[9];
+    kicker.training.set_focus(Some(AttributeTypes::Dexterity));
+    println!(
+        "{} works on their kicking, Dexterity advantage: {}",
+        kicker.name,
+        kicker.has_advantage.contains(&AttributeTypes::Dexterity)
+    );
+    let kicker = kicker.name.clone();
+    career.play_seasons(2);
+    let moved = career
+        .league
+        .teams
+        .iter()
+        .flat_map(|x| x.players.iter().map(move |p| (x, p)))
+        .find(|(_, p)| p.name == kicker);
+    match moved {
+        Some((team, kicker)) => println!(
+            "{} ({}, {}) Dexterity advantage: {}",
+            kicker.name,
+            kicker.age,
+            team.name,
+            kicker.has_advantage.contains(&AttributeTypes::Dexterity)
+        ),
+        None => println!("{} has left the league", kicker),
+    }
+    career.play_seasons(8);
+    for season in career.seasons.iter() {
+        let retired = season
+            .retirements
+            .iter()
+            .filter(|x| x.team == career.club)
+            .map(|x| format!("{} ({})", x.name, x.age))
+            .collect::<Vec<String>>();
+        let promoted = season
+            .promotions
+            .iter()
+            .filter(|x| x.0 == career.club)
+            .map(|x| x.1.clone())
+            .collect::<Vec<String>>();
+        let signed = season
+            .transfers
+            .iter()
+            .filter(|x| x.to == Some(career.club) && x.from.is_some())
+            .map(|x| format!("{} ({}k)", x.name, x.fee))
+            .collect::<Vec<String>>();
+        let accounts = season.accounts[career.club];
+        let deducted = season
+            .table
+            .iter()
+            .find(|x| x.team == career.club)
+            .unwrap()
+            .deducted;
+        println!(
+            "{}: champions {}, {} finished {}, retired: {}, promoted: {}, signed: {}, profit: {}k, deducted: {}",
+            season.year,
+            career.league.teams[season.champion().unwrap()].name,
+            career.club().name,
+            season.position(career.club).unwrap(),
+            retired.join(", "),
+            promoted.join(", "),
+            signed.join(", "),
+            accounts.profit(),
+            deducted
+        );
+    }
 }
@@ -0,0 +1,910 @@
+// Module to run a match between two teams
+
+pub mod match_engine {
+    use crate::{
+        coach::coach::{captain_call, MatchState, PenaltyOption, PhaseOption},
+        events::events::{
+            breakdown, dropgoal, kick_to_touch, line_out, line_out_maul_group, maul, open_play,
+            pass, penalty_goal, referee_rapport, scrum, BreakdownResult, LineOutCall,
+            LineOutResult, MaulResult, PassResult, PassType, PhaseResult, ScrumCall, ScrumResult,
+        },
+        pitch::pitch::PitchPosition,
+        player::player::{Player, Position, BACKS, FORWARDS},
+        random_engine::rng_eng::{rand_chance, rand_index, AttributeTypes, RollResult},
+        tactics::tactics::AttackWidth,
+        team::team::{Lineup, Team},
+    };
+
+    // Match clock in seconds
+    const HALF_SECONDS: u32 = 40 * 60;
+    const MATCH_SECONDS: u32 = 80 * 60;
+    // Knockout ties still level go to two periods of extra time, then sudden death
+    const EXTRA_TIME_HALF_SECONDS: u32 = 10 * 60;
+    const SUDDEN_DEATH_SECONDS: u32 = 10 * 60;
+    // Time taken by each piece of play
+    const PHASE_SECONDS: u32 = 25;
+    const SET_PIECE_SECONDS: u32 = 60;
+    const KICK_SECONDS: u32 = 45;
+    const SCORE_SECONDS: u32 = 90;
+
+    // Points on offer
+    pub const TRY_POINTS: i32 = 5;
+    pub const CONVERSION_POINTS: i32 = 2;
+    pub const PENALTY_POINTS: i32 = 3;
+    pub const DROP_GOAL_POINTS: i32 = 3;
+    pub const PENALTY_TRY_POINTS: i32 = 7;
+
+    // Advantage is over after a territorial gain or a number of phases
+    const ADVANTAGE_METRES: f32 = 10.;
+    const ADVANTAGE_PHASES: u32 = 4;
+
+    // Restart positions, metres from the receiving team's own line
+    const KICK_OFF_RECEIVE: f32 = 35.;
+    const DROP_OUT_RECEIVE: f32 = 55.;
+    // Conversions are taken back from the try line
+    const CONVERSION_DEPTH: f32 = 15.;
+
+    // Ten minutes in the sin bin for a yellow card
+    const SIN_BIN_SECONDS: u32 = 10 * 60;
+    // Chance of a yellow card for any penalty conceded
+    const CARD_CHANCE: f32 = 0.06;
+    // Referee warns a team after this many penalties, every one after is more likely a card
+    const PENALTY_WARNING: usize = 3;
+    const REPEAT_CARD_CHANCE: f32 = 0.05;
+    // Extra chance for a cynical penalty in their own 22
+    const CYNICAL_CARD_CHANCE: f32 = 0.12;
+    // Drop in the chance for each point of the captain's rapport with the referee
+    const CARD_RAPPORT_MOD: f32 = 0.03;
+
+    // Lift from the home crowd, until the match settles down
+    pub const HOME_MORALE: i32 = 1;
+    const HOME_MORALE_SECONDS: u32 = 20 * 60;
+
+    // Momentum, positive for the home side, and the amount worth a point in every contest
+    const MOMENTUM_MAX: f32 = 8.;
+    const MOMENTUM_PER_POINT: f32 = 4.;
+    // Momentum fades back to even every minute
+    const MOMENTUM_FADE: f32 = 1.;
+    // Swings from the big moments, for the side the event belongs to
+    const TRY_SWING: f32 = 4.;
+    const GOAL_SWING: f32 = 2.;
+    const SET_PIECE_SWING: f32 = 2.;
+    const TURNOVER_SWING: f32 = 2.;
+    const CARD_SWING: f32 = 3.;
+
+    // Kicking competition from the 22, in front of the posts and either side
+    const KICKING_COMPETITION_DEPTH: f32 = 22.;
+    const KICKING_COMPETITION_SPOTS: [f32; 3] = [0., 15., -15.];
+    const KICKING_COMPETITION_KICKS: usize = 5;
+    // Kickers go first, the rest of the team follow in sudden death
+    const KICKING_COMPETITION_ORDER: [Position; 5] = [
+        Position::FlyHalf,
+        Position::FullBack,
+        Position::InsideCentre,
+        Position::ScrumHalf,
+        Position::OutsideCentre,
+    ];
+
+    // Home or away team
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Side {
+        Home,
+        Away,
+    }
+
+    impl Side {
+        pub fn is_home(&self) -> bool {
+            *self == Side::Home
+        }
+
+        pub fn other(&self) -> Side {
+            match *self {
+                Side::Home => Side::Away,
+                Side::Away => Side::Home,
+            }
+        }
+    }
+
+    // Piece of play the match continues with
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Play {
+        KickOff(Side),         // Side kicking off
+        Phase(Side),           // Side in possession in open play
+        Carry(Side, Position), // Side in possession, player carrying without a pass
+        Scrum(Side),           // Side with the feed
+        LineOut(Side),         // Side with the throw
+        Penalty(Side),         // Side awarded the penalty
+        FreeKick(Side),        // Side awarded the free kick
+        DropOut(Side),         // Side restarting from their 22
+    }
+
+    // Advantage being played after an infringement
+    #[derive(Clone, Copy)]
+    pub struct Advantage {
+        pub side: Side,          // Non-offending side
+        pub mark: PitchPosition, // Where the penalty will be taken
+        pub phases: u32,
+    }
+
+    // Everything that can happen in a match
+    // Players are given by their squad index in the team the event belongs to
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum EventKind {
+        KickOff,
+        Scrum(ScrumResult),
+        LineOut(LineOutResult),
+        Maul(MaulResult),
+        Pass(PassResult),
+        Phase(PhaseResult),
+        Carry(usize, f32),   // Ball carrier and metres gained
+        Tackle(usize, bool), // Tackler and whether the tackle was made
+        Breakdown(BreakdownResult),
+        TerritoryKick(bool), // Kick from hand in open play, whether it found touch
+        KickToTouch(bool),   // Kick to touch from a penalty
+        AdvantagePlayed,
+        AdvantageOver,
+        PenaltyOption(PenaltyOption),
+        PenaltyGoal(usize, bool), // Kicker and whether it went over
+        DropGoal(usize, bool),
+        Conversion(usize, bool),
+        Try(usize), // Try scorer
+        PenaltyTry,
+        DropOut,
+        Substitution(usize, usize), // Squad index of the players off and on
+        YellowCard(usize),          // Player sent to the sin bin, belongs to their side
+        HalfTime,
+        FullTime,
+        ExtraTime,
+        SuddenDeath,
+        KickingCompetition(usize, bool), // Kicker and whether it went over, no points
+    }
+
+    // Entry in the match log
+    #[derive(Clone, Copy)]
+    pub struct MatchEvent {
+        pub clock: u32, // Seconds played
+        pub side: Side, // Side the event belongs to, usually in possession or awarded the decision
+        pub kind: EventKind,
+        pub roll: RollResult,
+        pub pos: PitchPosition,
+    }
+
+    impl MatchEvent {
+        pub fn minute(&self) -> u32 {
+            self.clock / 60
+        }
+    }
+
+    // Anything following the match as it is played
+    pub trait MatchObserver {
+        fn on_event(&mut self, event: &MatchEvent, home: &Team, away: &Team);
+    }
+
+    // Match between two teams
+    // Home attacks towards the far try line for the whole match
+    pub struct Match<'a> {
+        pub home: &'a mut Team,
+        pub away: &'a mut Team,
+        pub clock: u32,
+        pub pos: PitchPosition,
+        pub advantage: Option<Advantage>,
+        pub momentum: f32,             // Positive when the home side are on top
+        pub lineups: (Lineup, Lineup), // Home and away at kick off
+        pub events: Vec<MatchEvent>,
+        pub observers: Vec<&'a mut dyn MatchObserver>,
+    }
+
+    impl<'a> Match<'a> {
+        // New match, both teams start on zero
+        pub fn new(home: &'a mut Team, away: &'a mut Team) -> Match<'a> {
+            home.score = 0;
+            away.score = 0;
+            home.rest();
+            away.rest();
+            home.set_morale(HOME_MORALE);
+            away.set_morale(0);
+            let lineups = (home.lineup(), away.lineup());
+            Match {
+                home,
+                away,
+                clock: 0,
+                pos: PitchPosition::from_own_line(KICK_OFF_RECEIVE, &true),
+                advantage: None,
+                momentum: 0.,
+                lineups,
+                events: [].to_vec(),
+                observers: Vec::new(),
+            }
+        }
+
+        // Play the full 80 minutes
+        pub fn play(&mut self) {
+            self.play_until(Side::Home, HALF_SECONDS, false);
+            self.log(Side::Home, EventKind::HalfTime, RollResult::Flat);
+            // Away team kick off the second half
+            self.play_until(Side::Away, MATCH_SECONDS, false);
+            self.log(Side::Home, EventKind::FullTime, RollResult::Flat);
+        }
+
+        // Two periods of ten minutes, played in full
+        pub fn extra_time(&mut self) {
+            self.log(Side::Home, EventKind::ExtraTime, RollResult::Flat);
+            let end = self.clock.max(MATCH_SECONDS);
+            self.play_until(Side::Home, end + EXTRA_TIME_HALF_SECONDS, false);
+            self.play_until(Side::Away, end + 2 * EXTRA_TIME_HALF_SECONDS, false);
+            self.log(Side::Home, EventKind::FullTime, RollResult::Flat);
+        }
+
+        // First team to score wins, if anyone scores in the time
+        pub fn sudden_death(&mut self) {
+            self.log(Side::Home, EventKind::SuddenDeath, RollResult::Flat);
+            let end = self.clock + SUDDEN_DEATH_SECONDS;
+            self.play_until(Side::Home, end, true);
+            self.log(Side::Home, EventKind::FullTime, RollResult::Flat);
+        }
+
+        // Alternate kicks at goal from the 22, five each then sudden death
+        // Returns the winning side, no points are added to the score
+        pub fn kicking_competition(&mut self) -> Side {
+            let kickers = (self.kickers(Side::Home), self.kickers(Side::Away));
+            let mut made = (0, 0);
+            let mut kick = 0;
+            loop {
+                let mut spot = PitchPosition::from_own_line(KICKING_COMPETITION_DEPTH, &false);
+                spot.move_wide(KICKING_COMPETITION_SPOTS[kick % KICKING_COMPETITION_SPOTS.len()]);
+                for side in [Side::Home, Side::Away] {
+                    let kicker = match side {
+                        Side::Home => kickers.0[kick % kickers.0.len()],
+                        Side::Away => kickers.1[kick % kickers.1.len()],
+                    };
+                    let scored = penalty_goal(&self.team(side).players[kicker], &spot, &true);
+                    if scored {
+                        match side {
+                            Side::Home => made.0 += 1,
+                            Side::Away => made.1 += 1,
+                        }
+                    }
+                    self.log(
+                        side,
+                        EventKind::KickingCompetition(kicker, scored),
+                        RollResult::Flat,
+                    );
+                }
+                kick += 1;
+
+                // Decided once the trailing side cannot catch up in the first five
+                let left = KICKING_COMPETITION_KICKS.saturating_sub(kick);
+                if made.0 > made.1 + left {
+                    return Side::Home;
+                } else if made.1 > made.0 + left {
+                    return Side::Away;
+                }
+            }
+        }
+
+        // Side ahead on the scoreboard
+        pub fn leader(&self) -> Option<Side> {
+            match self.home.score - self.away.score {
+                d if d > 0 => Some(Side::Home),
+                d if d < 0 => Some(Side::Away),
+                _ => None,
+            }
+        }
+
+        // Play from a kick off until the clock runs out
+        // In sudden death play stops as soon as either side scores
+        fn play_until(&mut self, kick_off: Side, end: u32, sudden_death: bool) {
+            let score = (self.home.score, self.away.score);
+            let mut play = Play::KickOff(kick_off);
+            self.advantage = None;
+
+            while self.clock < end {
+                let start = self.clock;
+                let next = self.step(play);
+                play = self.check_advantage(next);
+                self.home.tire(self.clock - start);
+                self.away.tire(self.clock - start);
+                self.fade_momentum(self.clock - start);
+                if start < HOME_MORALE_SECONDS && self.clock >= HOME_MORALE_SECONDS {
+                    self.home.morale -= HOME_MORALE;
+                    self.shift_momentum(0.);
+                }
+                if sudden_death && score != (self.home.score, self.away.score) {
+                    break;
+                }
+            }
+        }
+
+        // Squad indices of the players taking part in a kicking competition
+        fn kickers(&self, side: Side) -> Vec<usize> {
+            let team = self.team(side);
+            let mut kickers = KICKING_COMPETITION_ORDER
+                .iter()
+                .flat_map(|&x| team.get_players(&[x]))
+                .map(|x| team.index_of(x))
+                .collect::<Vec<usize>>();
+            for (i, player) in team.players.iter().enumerate() {
+                if player.is_selected
+                    && player.selected_position != Position::Sub
+                    && !kickers.contains(&i)
+                {
+                    kickers.push(i);
+                }
+            }
+            // Anyone still in the sin bin misses out
+            kickers.retain(|&i| !team.players[i].in_sin_bin());
+            kickers
+        }
+
+        // Selected position and selection of each player on a side at kick off
+        pub fn lineup(&self, side: Side) -> &[(Position, bool)] {
+            match side {
+                Side::Home => &self.lineups.0,
+                Side::Away => &self.lineups.1,
+            }
+        }
+
+        // Team on the given side
+        pub fn team(&self, side: Side) -> &Team {
+            match side {
+                Side::Home => self.home,
+                Side::Away => self.away,
+            }
+        }
+
+        fn team_mut(&mut self, side: Side) -> &mut Team {
+            match side {
+                Side::Home => self.home,
+                Side::Away => self.away,
+            }
+        }
+
+        // State of the match as seen by the given side
+        pub fn state(&self, side: Side) -> MatchState {
+            MatchState {
+                minute: self.clock / 60,
+                score_diff: self.team(side).score - self.team(side.other()).score,
+                pos: self.pos,
+                is_home: side.is_home(),
+            }
+        }
+
+        // Record an event and pass it on to anyone following the match
+        fn log(&mut self, side: Side, kind: EventKind, roll: RollResult) {
+            let event = MatchEvent {
+                clock: self.clock,
+                side,
+                kind,
+                roll,
+                pos: self.pos,
+            };
+            for observer in self.observers.iter_mut() {
+                observer.on_event(&event, self.home, self.away);
+            }
+            self.events.push(event);
+
+            let swing = momentum_swing(&event);
+            if swing != 0. {
+                self.shift_momentum(match side {
+                    Side::Home => swing,
+                    Side::Away => -swing,
+                });
+            }
+        }
+
+        // Move the momentum towards one side and pass it on to the contests
+        fn shift_momentum(&mut self, change: f32) {
+            self.momentum = (self.momentum + change).clamp(-MOMENTUM_MAX, MOMENTUM_MAX);
+            let points = (self.momentum / MOMENTUM_PER_POINT) as i32;
+            self.home.set_momentum(points);
+            self.away.set_momentum(-points);
+        }
+
+        // Momentum dies away when nothing is happening
+        fn fade_momentum(&mut self, seconds: u32) {
+            let fade = (MOMENTUM_FADE * seconds as f32 / 60.).min(self.momentum.abs());
+            self.shift_momentum(-fade * self.momentum.signum());
+        }
+
+        // Resolve a single piece of play and return what follows
+        fn step(&mut self, play: Play) -> Play {
+            // Replacements are made while the ball is dead
+            if !matches!(play, Play::Phase(_) | Play::Carry(..)) {
+                self.substitutions(Side::Home);
+                self.substitutions(Side::Away);
+            }
+
+            match play {
+                Play::KickOff(side) => self.kick_off(side),
+                Play::Phase(side) => self.phase(side, None),
+                Play::Carry(side, carrier) => self.phase(side, Some(carrier)),
+                Play::Scrum(side) => self.scrum(side),
+                Play::LineOut(side) => self.line_out(side),
+                Play::Penalty(side) => self.penalty(side),
+                // Free kicks are tapped
+                Play::FreeKick(side) => Play::Phase(side),
+                Play::DropOut(side) => self.drop_out(side),
+            }
+        }
+
+        // Bring on replacements when the coach wants them
+        fn substitutions(&mut self, side: Side) {
+            let wanted = self.team(side).coach.replacements(&self.state(side));
+            let made = self
+                .events
+                .iter()
+                .filter(|x| x.side == side && matches!(x.kind, EventKind::Substitution(..)))
+                .count();
+
+            let bench = self.team(side).bench();
+            for on in bench.into_iter().take(wanted.saturating_sub(made)) {
+                if let Some(off) = self.team_mut(side).substitute(on) {
+                    self.log(side, EventKind::Substitution(off, on), RollResult::Flat);
+                }
+            }
+        }
+
+        // Advantage
+        // Played until the non-offending side make a territorial gain or the phases run out
+        // Losing the ball before then brings play back for the penalty
+        fn check_advantage(&mut self, next: Play) -> Play {
+            let Some(mut adv) = self.advantage else {
+                return next;
+            };
+
+            match next {
+                Play::Phase(side) | Play::Carry(side, _) if side == adv.side => {
+                    adv.phases += 1;
+                    let gained = self.pos.own_line_dist(&side.is_home())
+                        - adv.mark.own_line_dist(&side.is_home());
+                    if gained >= ADVANTAGE_METRES || adv.phases >= ADVANTAGE_PHASES {
+                        self.advantage = None;
+                        self.log(side, EventKind::AdvantageOver, RollResult::Flat);
+                    } else {
+                        self.advantage = Some(adv);
+                    }
+                    next
+                }
+                // Points on the board are always a gain
+                Play::KickOff(_) => {
+                    self.advantage = None;
+                    next
+                }
+                _ => {
+                    self.advantage = None;
+                    self.pos = adv.mark;
+                    Play::Penalty(adv.side)
+                }
+            }
+        }
+
+        // Infringement against the side in possession
+        // Their coach chooses to play on or take the penalty
+        fn award_advantage(&mut self, side: Side) -> Play {
+            if self.advantage.is_some() || !self.team(side).coach.play_advantage(&self.state(side))
+            {
+                return Play::Penalty(side);
+            }
+
+            self.advantage = Some(Advantage {
+                side,
+                mark: self.pos,
+                phases: 0,
+            });
+            self.log(side, EventKind::AdvantagePlayed, RollResult::Flat);
+            Play::Phase(side)
+        }
+
+        // Kick off
+        // Receiving team have to gather the ball
+        fn kick_off(&mut self, side: Side) -> Play {
+            self.clock += KICK_SECONDS;
+            let receiving = side.other();
+            self.pos = PitchPosition::from_own_line(KICK_OFF_RECEIVE, &receiving.is_home());
+
+            let catch = self
+                .team(receiving)
+                .active_player(Position::SecondRow1)
+                .challange_roll(&AttributeTypes::Dexterity);
+            self.log(side, EventKind::KickOff, catch.1);
+
+            match catch.1 {
+                RollResult::CriticalFail => Play::Scrum(side),
+                _ => Play::Phase(receiving),
+            }
+        }
+
+        // Phase of open play
+        // Scrum half passes to a carrier unless someone is already carrying
+        fn phase(&mut self, side: Side, carrier: Option<Position>) -> Play {
+            self.clock += PHASE_SECONDS;
+            let is_home = side.is_home();
+
+            // Coach decides what to do with ball in hand, within the game plan
+            if carrier.is_none() {
+                let state = self.state(side);
+                let team = self.team(side);
+                match team
+                    .tactics
+                    .phase_option(team.coach.phase_option(&state), &state)
+                {
+                    PhaseOption::Kick => return self.kick_for_territory(side),
+                    PhaseOption::DropGoal => return self.drop_goal(side),
+                    PhaseOption::Run => {}
+                }
+            }
+
+            let mut pos = self.pos;
+            let att = self.team(side);
+            let def = self.team(side.other());
+
+            let carrier = match carrier {
+                Some(carrier) => att.active_player(carrier),
+                None => {
+                    let carrier = pick_carrier(att);
+                    let pass_type = match (
+                        FORWARDS.contains(&carrier.selected_position),
+                        att.tactics.width,
+                    ) {
+                        (true, _) | (_, AttackWidth::Narrow) => PassType::Short,
+                        (false, AttackWidth::Wide) => PassType::Skip,
+                        (false, AttackWidth::Standard) => PassType::Long,
+                    };
+                    let (res, roll) = pass(
+                        att.active_player(Position::ScrumHalf),
+                        carrier,
+                        def.active_player(carrier.selected_position),
+                        pass_type,
+                        &mut pos,
+                        &is_home,
+                    );
+                    if res != PassResult::Clean {
+                        self.pos = pos;
+                        self.log(side, EventKind::Pass(res), roll);
+                        return match res {
+                            PassResult::Intercept => Play::Phase(side.other()),
+                            _ => Play::Scrum(side.other()),
+                        };
+                    }
+                    carrier
+                }
+            };
+
+            let phase = open_play(att, def, carrier, &mut pos, &is_home);
+            let (result, roll) = (phase.result, phase.roll);
+            let metres = phase.metres / phase.carriers.len() as f32;
+            let carriers = phase
+                .carriers
+                .iter()
+                .map(|x| att.index_of(x))
+                .collect::<Vec<usize>>();
+            let tackles = phase
+                .tackles
+                .iter()
+                .map(|(x, made)| (def.index_of(x), *made))
+                .collect::<Vec<(usize, bool)>>();
+            let ruck = match result {
+                PhaseResult::Tackled | PhaseResult::DominantTackle | PhaseResult::CoverTackle => {
+                    let carrier = phase.carriers[phase.carriers.len() - 1];
+                    Some(breakdown(
+                        att,
+                        def,
+                        carrier,
+                        att.tactics.ruck_numbers,
+                        def.tactics.contest_numbers,
+                    ))
+                }
+                _ => None,
+            };
+
+            self.pos = pos;
+            self.log(side, EventKind::Phase(result), roll);
+            for carrier in carriers.iter() {
+                self.log(side, EventKind::Carry(*carrier, metres), RollResult::Flat);
+            }
+            for (tackler, made) in tackles {
+                self.log(
+                    side.other(),
+                    EventKind::Tackle(tackler, made),
+                    RollResult::Flat,
+                );
+            }
+
+            match result {
+                PhaseResult::Try => self.try_scored(side, carriers[carriers.len() - 1]),
+                PhaseResult::Handling(PassResult::Intercept) => Play::Phase(side.other()),
+                PhaseResult::Handling(_) => Play::Scrum(side.other()),
+                _ => {
+                    let (res, roll) = ruck.unwrap();
+                    self.clock += PHASE_SECONDS;
+                    self.log(side, EventKind::Breakdown(res), roll);
+                    match res {
+                        BreakdownResult::Retained => Play::Phase(side),
+                        BreakdownResult::Turnover => Play::Phase(side.other()),
+                        BreakdownResult::PenaltyAttack => self.award_advantage(side),
+                        BreakdownResult::PenaltyDefence => Play::Penalty(side.other()),
+                    }
+                }
+            }
+        }
+
+        // Kick from hand to touch, the opposition have the throw
+        fn kick_for_territory(&mut self, side: Side) -> Play {
+            self.clock += KICK_SECONDS;
+            let mut pos = self.pos;
+            let kicker = self.team(side).active_player(Position::FlyHalf);
+            let (found, roll) = kick_to_touch(kicker, &mut pos, &side.is_home());
+            self.pos = pos;
+            self.log(side, EventKind::TerritoryKick(found), roll);
+
+            match found {
+                true => Play::LineOut(side.other()),
+                false => Play::Phase(side.other()),
+            }
+        }
+
+        // Drop goal with the forwards protecting the kicker
+        fn drop_goal(&mut self, side: Side) -> Play {
+            self.clock += KICK_SECONDS;
+            let team = self.team(side);
+            let kicker = team.active_player(Position::FlyHalf);
+            let scored = dropgoal(
+                kicker,
+                &self.pos,
+                &side.is_home(),
+                team.get_players(&FORWARDS),
+            );
+            let kicker = team.index_of(kicker);
+            self.log(side, EventKind::DropGoal(kicker, scored), RollResult::Flat);
+
+            match scored {
+                true => {
+                    self.team_mut(side).score += DROP_GOAL_POINTS;
+                    Play::KickOff(side.other())
+                }
+                false => Play::DropOut(side.other()),
+            }
+        }
+
+        // Scrum, the preferred call is saved for the opposition 22
+        fn scrum(&mut self, side: Side) -> Play {
+            self.clock += SET_PIECE_SECONDS;
+            let call = match self.pos.in_own_22(&!side.is_home()) {
+                true => self.team(side).tactics.scrum_call,
+                false => ScrumCall::Standard,
+            };
+            let (res, roll) = scrum(
+                self.team(side),
+                self.team(side.other()),
+                &self.pos,
+                &side.is_home(),
+                call,
+            );
+            self.log(side, EventKind::Scrum(res), roll);
+
+            match res {
+                ScrumResult::AttackBall => Play::Phase(side),
+                ScrumResult::Number8PickUp => Play::Carry(side, Position::Number8),
+                ScrumResult::DefenceBall => Play::Phase(side.other()),
+                ScrumResult::PenaltyAttack => Play::Penalty(side),
+                ScrumResult::PenaltyDefence => Play::Penalty(side.other()),
+                ScrumResult::FreeKickAttack => Play::FreeKick(side),
+                ScrumResult::FreeKickDefence => Play::FreeKick(side.other()),
+            }
+        }
+
+        // Line out, driving maul when in the opposition 22 if the tactics call for it
+        fn line_out(&mut self, side: Side) -> Play {
+            self.clock += SET_PIECE_SECONDS;
+            let is_home = side.is_home();
+            let preferred = self.team(side).tactics.line_out;
+            let call = LineOutCall {
+                maul: preferred.maul && self.pos.in_own_22(&!is_home),
+                ..preferred
+            };
+
+            let (res, roll) = line_out(self.team(side), self.team(side.other()), &call);
+            self.log(side, EventKind::LineOut(res), roll);
+
+            match res {
+                LineOutResult::CleanCatch => Play::Phase(side),
+                LineOutResult::Stolen => Play::Phase(side.other()),
+                LineOutResult::NotStraight => Play::Scrum(side.other()),
+                LineOutResult::Maul => {
+                    let mut pos = self.pos;
+                    let (res, roll) = maul(
+                        line_out_maul_group(self.team(side), &call),
+                        line_out_maul_group(self.team(side.other()), &call),
+                        &mut pos,
+                        &is_home,
+                    );
+                    self.pos = pos;
+                    self.log(side, EventKind::Maul(res), roll);
+
+                    match res {
+                        MaulResult::Try => {
+                            // Hooker at the back of the maul gets the ball down
+                            let team = self.team(side);
+                            self.try_scored(
+                                side,
+                                team.index_of(team.active_player(Position::Hooker)),
+                            )
+                        }
+                        MaulResult::PenaltyTry => self.penalty_try(side),
+                        MaulResult::Penalty => Play::Penalty(side),
+                        MaulResult::BallOut => Play::Phase(side),
+                        MaulResult::Turnover => Play::Scrum(side.other()),
+                    }
+                }
+            }
+        }
+
+        // Referee's call on a card for the side conceding a penalty
+        // Repeat offending and cynical penalties in their own 22 are punished
+        // A captain with the referee's ear keeps their players on the pitch
+        fn discipline(&mut self, offending: Side) {
+            let conceded = self
+                .events
+                .iter()
+                .filter(|x| {
+                    x.side == offending.other() && matches!(x.kind, EventKind::PenaltyOption(_))
+                })
+                .count();
+            let mut chance =
+                CARD_CHANCE + conceded.saturating_sub(PENALTY_WARNING) as f32 * REPEAT_CARD_CHANCE;
+            if self.pos.in_own_22(&offending.is_home()) {
+                chance += CYNICAL_CARD_CHANCE;
+            }
+            let rapport = referee_rapport(self.team(offending));
+            chance *= (1. - rapport as f32 * CARD_RAPPORT_MOD).max(0.);
+            if !rand_chance(chance) {
+                return;
+            }
+
+            // Forwards give away most penalties
+            let team = self.team(offending);
+            let offenders = team
+                .get_players(&FORWARDS)
+                .into_iter()
+                .filter(|x| !x.in_sin_bin())
+                .map(|x| team.index_of(x))
+                .collect::<Vec<usize>>();
+            if offenders.is_empty() {
+                return;
+            }
+            let offender = offenders[rand_index(offenders.len())];
+            self.team_mut(offending).sin_bin(offender, SIN_BIN_SECONDS);
+            self.log(offending, EventKind::YellowCard(offender), RollResult::Flat);
+        }
+
+        // Penalty, the awarded side's coach picks the option and the captain makes the call
+        fn penalty(&mut self, side: Side) -> Play {
+            self.clock += KICK_SECONDS;
+            self.discipline(side.other());
+            let is_home = side.is_home();
+            let state = self.state(side);
+            let team = self.team(side);
+            let read = team.captain().challange_roll(&AttributeTypes::Wisdom);
+            let option = captain_call(team.coach.penalty_option(&state), &state, read);
+            self.log(side, EventKind::PenaltyOption(option), RollResult::Flat);
+
+            match option {
+                PenaltyOption::KickAtGoal => {
+                    let team = self.team(side);
+                    let kicker = team.active_player(Position::FlyHalf);
+                    let scored = penalty_goal(kicker, &self.pos, &is_home);
+                    let kicker = team.index_of(kicker);
+                    self.log(
+                        side,
+                        EventKind::PenaltyGoal(kicker, scored),
+                        RollResult::Flat,
+                    );
+                    match scored {
+                        true => {
+                            self.team_mut(side).score += PENALTY_POINTS;
+                            Play::KickOff(side.other())
+                        }
+                        false => Play::DropOut(side.other()),
+                    }
+                }
+                PenaltyOption::KickToTouch => {
+                    let mut pos = self.pos;
+                    let kicker = self.team(side).active_player(Position::FlyHalf);
+                    let (found, roll) = kick_to_touch(kicker, &mut pos, &is_home);
+                    self.pos = pos;
+                    self.log(side, EventKind::KickToTouch(found), roll);
+                    match found {
+                        true => Play::LineOut(side),
+                        false => Play::Phase(side.other()),
+                    }
+                }
+                PenaltyOption::TapAndGo => Play::Phase(side),
+                PenaltyOption::Scrum => Play::Scrum(side),
+            }
+        }
+
+        // 22 drop out, kicked long to the opposition
+        fn drop_out(&mut self, side: Side) -> Play {
+            self.clock += KICK_SECONDS;
+            let receiving = side.other();
+            self.pos = PitchPosition::from_own_line(DROP_OUT_RECEIVE, &receiving.is_home());
+            self.log(side, EventKind::DropOut, RollResult::Flat);
+            Play::Phase(receiving)
+        }
+
+        // Try and conversion from in front of where it was scored
+        fn try_scored(&mut self, side: Side, scorer: usize) -> Play {
+            self.clock += SCORE_SECONDS;
+            let is_home = side.is_home();
+            self.team_mut(side).score += TRY_POINTS;
+            self.log(side, EventKind::Try(scorer), RollResult::Flat);
+
+            let mut spot = self.pos;
+            spot.advance(-CONVERSION_DEPTH, &is_home);
+            let team = self.team(side);
+            let kicker = team.active_player(Position::FlyHalf);
+            let converted = penalty_goal(kicker, &spot, &is_home);
+            let kicker = team.index_of(kicker);
+            if converted {
+                self.team_mut(side).score += CONVERSION_POINTS;
+            }
+            self.log(
+                side,
+                EventKind::Conversion(kicker, converted),
+                RollResult::Flat,
+            );
+
+            // Conceding team restart
+            Play::KickOff(side.other())
+        }
+
+        fn penalty_try(&mut self, side: Side) -> Play {
+            self.clock += SCORE_SECONDS;
+            self.team_mut(side).score += PENALTY_TRY_POINTS;
+            self.log(side, EventKind::PenaltyTry, RollResult::Flat);
+            Play::KickOff(side.other())
+        }
+    }
+
+    // Momentum swing from an event, for the side it belongs to
+    // Scores, critical rolls at the set piece and losing the ball swing a match
+    fn momentum_swing(event: &MatchEvent) -> f32 {
+        match (event.kind, event.roll) {
+            (EventKind::Try(_) | EventKind::PenaltyTry, _) => TRY_SWING,
+            (EventKind::PenaltyGoal(_, true) | EventKind::DropGoal(_, true), _) => GOAL_SWING,
+            (
+                EventKind::Breakdown(BreakdownResult::Turnover)
+                | EventKind::Pass(PassResult::Intercept)
+                | EventKind::Phase(PhaseResult::Handling(PassResult::Intercept))
+                | EventKind::LineOut(LineOutResult::Stolen),
+                _,
+            ) => -TURNOVER_SWING,
+            (
+                EventKind::Scrum(_) | EventKind::LineOut(_) | EventKind::Maul(_),
+                RollResult::CriticalSuccess,
+            ) => SET_PIECE_SWING,
+            (
+                EventKind::Scrum(_) | EventKind::LineOut(_) | EventKind::Maul(_),
+                RollResult::CriticalFail,
+            ) => -SET_PIECE_SWING,
+            (EventKind::YellowCard(_), _) => -CARD_SWING,
+            _ => 0.,
+        }
+    }
+
+    // Random ball carrier from anyone but the scrum half
+    // Narrow attacks use the forwards, wide attacks the backs
+    fn pick_carrier(team: &Team) -> &Player {
+        let pool = match team.tactics.width {
+            AttackWidth::Narrow => FORWARDS.to_vec(),
+            AttackWidth::Standard => [&FORWARDS[..], &BACKS[..]].concat(),
+            AttackWidth::Wide => BACKS.to_vec(),
+        };
+        let carriers = team
+            .get_players(&pool)
+            .into_iter()
+            .filter(|x| x.selected_position != Position::ScrumHalf && !x.in_sin_bin())
+            .collect::<Vec<_>>();
+        carriers[rand_index(carriers.len())]
+    }
+}
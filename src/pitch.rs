@@ -11,6 +11,7 @@ pub mod pitch {
     const AWAY_5M: f32 = 95.;
     const HALF_WAY: f32 = 50.;
 
+    #[derive(Clone, Copy)]
     pub struct PitchPosition {
         x: f32,
         y: f32,
@@ -21,6 +22,102 @@ pub mod pitch {
             PitchPosition { x: 0., y: 0. }
         }
 
+        // New position at the given coordinates, kept inside the pitch
+        pub fn at(x: f32, y: f32) -> PitchPosition {
+            PitchPosition {
+                x: x.clamp(0., PITCH_LENGTH),
+                y: y.clamp(0., PITCH_WIDTH),
+            }
+        }
+
+        // New position the given distance from the team's own try line, in the middle of the pitch
+        pub fn from_own_line(dist: f32, is_home: &bool) -> PitchPosition {
+            let mut pos = match *is_home {
+                true => PitchPosition::at(0., 0.5 * PITCH_WIDTH),
+                false => PitchPosition::at(PITCH_LENGTH, 0.5 * PITCH_WIDTH),
+            };
+            pos.advance(dist, is_home);
+            pos
+        }
+
+        pub fn x(&self) -> f32 {
+            self.x
+        }
+
+        pub fn y(&self) -> f32 {
+            self.y
+        }
+
+        // Distance from the team's own try line
+        // The home team defends x = 0 and attacks x = PITCH_LENGTH
+        pub fn own_line_dist(&self, is_home: &bool) -> f32 {
+            match *is_home {
+                true => self.x,
+                false => PITCH_LENGTH - self.x,
+            }
+        }
+
+        // Distance to the opposition try line
+        pub fn opp_line_dist(&self, is_home: &bool) -> f32 {
+            PITCH_LENGTH - self.own_line_dist(is_home)
+        }
+
+        // Has the team reached the opposition try line
+        pub fn over_try_line(&self, is_home: &bool) -> bool {
+            self.opp_line_dist(is_home) <= 0.
+        }
+
+        // Move the position towards the opposition try line
+        // Negative metres move the team backwards
+        pub fn advance(&mut self, metres: f32, is_home: &bool) {
+            let x = match *is_home {
+                true => self.x + metres,
+                false => self.x - metres,
+            };
+            self.x = x.clamp(0., PITCH_LENGTH);
+        }
+
+        // Move the position across the pitch towards the open side
+        pub fn move_wide(&mut self, metres: f32) {
+            let y = match self.y < 0.5 * PITCH_WIDTH {
+                true => self.y + metres,
+                false => self.y - metres,
+            };
+            self.y = y.clamp(0., PITCH_WIDTH);
+        }
+
+        // Move the position to the nearest touch line
+        pub fn to_touch(&mut self) {
+            self.y = match self.y < 0.5 * PITCH_WIDTH {
+                true => 0.,
+                false => PITCH_WIDTH,
+            };
+        }
+
+        // Is the position inside the team's own 5m
+        pub fn in_own_5m(&self, is_home: &bool) -> bool {
+            match *is_home {
+                true => self.x <= HOME_5M,
+                false => self.x >= AWAY_5M,
+            }
+        }
+
+        // Is the position inside the team's own 22
+        pub fn in_own_22(&self, is_home: &bool) -> bool {
+            match *is_home {
+                true => self.x <= HOME_22,
+                false => self.x >= AWAY_22,
+            }
+        }
+
+        // Is the position inside the team's own half
+        pub fn in_own_half(&self, is_home: &bool) -> bool {
+            match *is_home {
+                true => self.x < HALF_WAY,
+                false => self.x > HALF_WAY,
+            }
+        }
+
         // Find the angle to the goal
         pub fn goal_angle(&self, is_home: &bool) -> f32 {
             if self.x == 0. {
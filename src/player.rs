@@ -2,14 +2,41 @@ pub mod player {
     use std::fmt; // Import `fmt`
 
     use crate::{
-        random_engine::rnd_name::NameGenerator,
-        random_engine::rng_eng::{AttributeTypes, Attributes, RollResult, RollType},
+        random_engine::rnd_name::{NameGenerator, Nationality},
+        random_engine::rng_eng::{
+            rand_index, AttributeTypes, Attributes, RollResult, RollType, ATTRIBUTE_MAX,
+        },
+        training::training::TrainingProgress,
+        transfer::transfer::Contract,
     };
 
-    #[derive(Clone, PartialEq)]
+    // Match ratings kept for a player's form
+    const RECENT_RATINGS: usize = 5;
+
+    // Ages of the players in a new senior squad
+    pub const MIN_SENIOR_AGE: u8 = 19;
+    pub const MAX_SENIOR_AGE: u8 = 33;
+
+    // Players vary this much either side of the typical build for their position
+    const HEIGHT_SPREAD: u32 = 5; // cm
+    const WEIGHT_SPREAD: u32 = 7; // kg
+                                  // Build everything else is measured against
+    pub const REFERENCE_WEIGHT: i32 = 100;
+    // Extra weight worth a point of Strength when working out power to weight
+    const POWER_KG_PER_POINT: i32 = 8;
+    // Fatigue built up over a full match, as a penalty to every roll
+    const FATIGUE_PER_MINUTE: f32 = 1. / 30.;
+    // Change in the rate of tiring for each point of power to weight and Constitution
+    const FATIGUE_POWER_MOD: f32 = 0.05;
+    const FATIGUE_CONSTITUTION_MOD: f32 = 0.05;
+    // Even the fittest players tire a little
+    const MIN_FATIGUE_RATE: f32 = 0.25;
+    // Change in the risk of injury for each point of Constitution
+    const INJURY_CONSTITUTION_MOD: f32 = 0.06;
+
+    #[derive(Clone, Copy, PartialEq)]
     #[allow(dead_code)]
     // Enum of the available positions
-
     pub enum Position {
         LooseHead,
         TightHead,
@@ -40,6 +67,57 @@ pub mod player {
         Position::Number8,
     ];
 
+    // Half backs, centres and back three
+    pub const BACKS: [Position; 7] = [
+        Position::ScrumHalf,
+        Position::FlyHalf,
+        Position::InsideCentre,
+        Position::OutsideCentre,
+        Position::LeftWinger,
+        Position::RightWinger,
+        Position::FullBack,
+    ];
+
+    // Props and hooker, first to engage at the scrum
+    pub const FRONT_ROW: [Position; 3] =
+        [Position::LooseHead, Position::Hooker, Position::TightHead];
+
+    // Locks, pushing behind the front row
+    pub const SECOND_ROW: [Position; 2] = [Position::SecondRow1, Position::SecondRow2];
+
+    // Flankers and number 8
+    pub const BACK_ROW: [Position; 3] = [
+        Position::BlindSideFlanker,
+        Position::OpenSideFlanker,
+        Position::Number8,
+    ];
+
+    // Typical height in cm and weight in kg for a position
+    pub fn typical_build(pos: Position) -> (u32, u32) {
+        match pos {
+            Position::LooseHead | Position::TightHead => (184, 120),
+            Position::Hooker => (180, 108),
+            Position::SecondRow1 | Position::SecondRow2 => (200, 117),
+            Position::BlindSideFlanker | Position::OpenSideFlanker => (190, 107),
+            Position::Number8 => (192, 112),
+            Position::ScrumHalf => (174, 80),
+            Position::FlyHalf => (181, 88),
+            Position::InsideCentre | Position::OutsideCentre => (185, 98),
+            Position::LeftWinger | Position::RightWinger => (183, 92),
+            Position::FullBack => (186, 93),
+            Position::Sub => (185, REFERENCE_WEIGHT as u32),
+        }
+    }
+
+    // Body type from the body mass index
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum BodyType {
+        Lean,
+        Athletic,
+        Powerful,
+        Heavy,
+    }
+
     pub fn get_position(num: u8) -> Position {
         match num {
             1 => Position::LooseHead,
@@ -112,34 +190,141 @@ pub mod player {
     pub struct Player {
         pub age: u8,
         pub name: String,
+        pub nationality: Nationality,
         pub position: Vec<Position>,
-        pub weight: u32,
+        pub height: u32, // cm
+        pub weight: u32, // kg
         pub attributes: Attributes,
         pub has_advantage: Vec<AttributeTypes>,
         pub has_disadvantage: Vec<AttributeTypes>,
         pub is_selected: bool,
         pub selected_position: Position,
+        pub potential: i8, // Highest score any attribute can develop to
+        pub games: u32,    // Games played this season
+        pub injury_weeks: u32,
+        pub training: TrainingProgress,
+        pub contract: Contract,
+        pub ratings: Vec<f32>, // Most recent match ratings, oldest first
+        pub fatigue: f32,      // Built up during a match
+        pub sin_bin: u32,      // Seconds left in the sin bin
+        pub morale: i32,       // Team morale and momentum, added once to each contest
     }
 
     impl Player {
         // Default empty player
         pub fn new() -> Player {
-            Player {
-                age: 0,
+            let mut player = Player {
+                age: MIN_SENIOR_AGE
+                    + rand_index((MAX_SENIOR_AGE - MIN_SENIOR_AGE + 1).into()) as u8,
                 // name : "".to_string(),
-                name: NameGenerator::new().get_name(),
+                name: NameGenerator::new().get_name(Nationality::Irish),
+                nationality: Nationality::Irish,
                 position: [].to_vec(),
+                height: 0,
                 weight: 0,
                 attributes: Attributes::new(),
                 has_advantage: [].to_vec(),
                 has_disadvantage: [].to_vec(),
                 is_selected: true,
                 selected_position: Position::Sub,
+                potential: ATTRIBUTE_MAX,
+                games: 0,
+                injury_weeks: 0,
+                training: TrainingProgress::new(),
+                contract: Contract::new(),
+                ratings: [].to_vec(),
+                fatigue: 0.,
+                sin_bin: 0,
+                morale: 0,
+            };
+            player.set_build();
+            player
+        }
+
+        // Height and weight around the typical build for the player's main position
+        pub fn set_build(&mut self) {
+            let pos = self.position.first().copied().unwrap_or(Position::Sub);
+            let (height, weight) = typical_build(pos);
+            let spread = |x: u32| rand_index(2 * x as usize + 1) as u32;
+            self.height = height - HEIGHT_SPREAD + spread(HEIGHT_SPREAD);
+            self.weight = weight - WEIGHT_SPREAD + spread(WEIGHT_SPREAD);
+        }
+
+        pub fn body_type(&self) -> BodyType {
+            let metres = self.height as f32 / 100.;
+            match self.weight as f32 / (metres * metres) {
+                bmi if bmi < 26. => BodyType::Lean,
+                bmi if bmi < 29. => BodyType::Athletic,
+                bmi if bmi < 33. => BodyType::Powerful,
+                _ => BodyType::Heavy,
+            }
+        }
+
+        // Weight against the reference build, in kg
+        pub fn mass(&self) -> i32 {
+            self.weight as i32 - REFERENCE_WEIGHT
+        }
+
+        // Strength for the weight carried, light and strong players score highest
+        pub fn power_to_weight(&self) -> i32 {
+            self.attributes.get(AttributeTypes::Strength) as i32 - self.mass() / POWER_KG_PER_POINT
+        }
+
+        // Tire over time on the pitch
+        // Players with poor power to weight or little stamina tire fastest
+        pub fn tire(&mut self, seconds: u32) {
+            let stamina = self.attributes.get(AttributeTypes::Constitution) as f32;
+            let rate = (1.
+                - self.power_to_weight() as f32 * FATIGUE_POWER_MOD
+                - stamina * FATIGUE_CONSTITUTION_MOD)
+                .max(MIN_FATIGUE_RATE);
+            self.fatigue += seconds as f32 / 60. * FATIGUE_PER_MINUTE * rate;
+        }
+
+        // Keep a match rating, forgetting the oldest
+        pub fn add_rating(&mut self, rating: f32) {
+            self.ratings.push(rating);
+            if self.ratings.len() > RECENT_RATINGS {
+                self.ratings.remove(0);
             }
         }
 
-        // Challange roll for this player
+        // Average of the recent match ratings
+        pub fn form(&self) -> Option<f32> {
+            match self.ratings.is_empty() {
+                true => None,
+                false => Some(self.ratings.iter().sum::<f32>() / self.ratings.len() as f32),
+            }
+        }
+
+        // Scale on the risk of injury, a strong Constitution shrugs off knocks
+        pub fn injury_risk(&self) -> f32 {
+            let constitution = self.attributes.get(AttributeTypes::Constitution) as f32;
+            (1. - constitution * INJURY_CONSTITUTION_MOD).max(0.)
+        }
+
+        // Whether the player is off the pitch serving a yellow card
+        pub fn in_sin_bin(&self) -> bool {
+            self.sin_bin > 0
+        }
+
+        // Challange roll for this player, lifted or dropped by the team's morale
         pub fn challange_roll(&self, attr: &AttributeTypes) -> (i32, RollResult) {
+            let (score, res) = self.support_roll(attr);
+            match self.in_sin_bin() {
+                true => (score, res),
+                false => (score + self.morale, res),
+            }
+        }
+
+        // Roll in support of a contest, without the team's morale
+        // The contest takes morale once rather than from every player in it
+        pub fn support_roll(&self, attr: &AttributeTypes) -> (i32, RollResult) {
+            // Nobody fills the gap left by a player in the sin bin
+            if self.in_sin_bin() {
+                return (0, RollResult::Flat);
+            }
+
             // Should this be rewritten as a match?
             // Check if the player has advantage or disadvantage on the roll
             // Player will have advantage only if they can have advantage on the attribute and they can play the position
@@ -155,7 +340,43 @@ pub mod player {
                 RollType::Flat
             };
 
-            self.attributes.challange_roll(*attr, roll_type)
+            // Tired players lose a point for every point of fatigue
+            let (score, res) = self.attributes.challange_roll(*attr, roll_type);
+            (score - self.fatigue as i32, res)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn fittest_players_still_tire() {
+            let mut player = Player::new();
+            player
+                .attributes
+                .set(AttributeTypes::Constitution, ATTRIBUTE_MAX);
+            player
+                .attributes
+                .set(AttributeTypes::Strength, ATTRIBUTE_MAX);
+            player.weight = (REFERENCE_WEIGHT - 20) as u32;
+            player.tire(80 * 60);
+            let least = 80. * FATIGUE_PER_MINUTE * MIN_FATIGUE_RATE;
+            assert!((player.fatigue - least).abs() < 1e-4, "{}", player.fatigue);
+        }
+
+        #[test]
+        fn poor_stamina_tires_faster() {
+            let mut fit = Player::new();
+            let mut unfit = Player::new();
+            for (player, stamina) in [(&mut fit, 5), (&mut unfit, -5)] {
+                player.attributes.set(AttributeTypes::Constitution, stamina);
+                player.attributes.set(AttributeTypes::Strength, 0);
+                player.weight = REFERENCE_WEIGHT as u32;
+            }
+            fit.tire(60 * 60);
+            unfit.tire(60 * 60);
+            assert!(unfit.fatigue > fit.fatigue);
         }
     }
 }
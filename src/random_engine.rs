@@ -8,6 +8,7 @@ pub mod rng_eng {
 
     // Maybe usefull later when actually talking about rolls
     #[allow(dead_code)]
+    #[derive(Clone, Copy, PartialEq)]
     pub enum RollType {
         Flat,
         Advantage,
@@ -15,7 +16,7 @@ pub mod rng_eng {
     }
 
     // Roll result, allowing for special events based on the roll result
-    #[derive(Debug)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
     pub enum RollResult {
         CriticalFail,    // roll 1
         CriticalSuccess, // roll 20
@@ -48,8 +49,20 @@ pub mod rng_eng {
         ((dice + attr / 2).into(), roll_result)
     }
 
+    // Random index into a collection of the given length
+    pub fn rand_index(len: usize) -> usize {
+        let mut rng = rand::thread_rng();
+        Uniform::from(0..len).sample(&mut rng)
+    }
+
+    // True with the given probability
+    pub fn rand_chance(chance: f32) -> bool {
+        let mut rng = rand::thread_rng();
+        Uniform::from(0.0..1.0).sample(&mut rng) < chance
+    }
+
     #[allow(dead_code)]
-    #[derive(Clone, Copy, PartialEq)]
+    #[derive(Debug, Clone, Copy, PartialEq)]
     // #[derive(PartialEq)]
     pub enum AttributeTypes {
         Strength,
@@ -60,6 +73,20 @@ pub mod rng_eng {
         Charisma,
     }
 
+    // Every attribute, in order
+    pub const ATTRIBUTE_TYPES: [AttributeTypes; 6] = [
+        AttributeTypes::Strength,
+        AttributeTypes::Constitution,
+        AttributeTypes::Dexterity,
+        AttributeTypes::Intelligence,
+        AttributeTypes::Wisdom,
+        AttributeTypes::Charisma,
+    ];
+
+    // Limits on an attribute score
+    pub const ATTRIBUTE_MIN: i8 = -10;
+    pub const ATTRIBUTE_MAX: i8 = 10;
+
     // Attributes
     // For the moment I'll use dnd-like attributes
     #[allow(dead_code)]
@@ -87,77 +114,653 @@ pub mod rng_eng {
             }
         }
 
-        // Implement a Challange Roll
-        pub fn challange_roll(
-            &self,
-            att_type: AttributeTypes,
-            roll_type: RollType,
-        ) -> (i32, RollResult) {
-            let att = match att_type {
+        // Random attributes, each between the given scores
+        pub fn random(min: i8, max: i8) -> Attributes {
+            let mut attributes = Attributes::new();
+            for attr in ATTRIBUTE_TYPES {
+                attributes.set(attr, min + rand_index((max - min + 1) as usize) as i8);
+            }
+            attributes
+        }
+
+        // Score for a single attribute
+        pub fn get(&self, att_type: AttributeTypes) -> i8 {
+            match att_type {
                 AttributeTypes::Strength => self.stre,
                 AttributeTypes::Constitution => self.cons,
                 AttributeTypes::Dexterity => self.dext,
                 AttributeTypes::Intelligence => self.inte,
                 AttributeTypes::Wisdom => self.wisd,
                 AttributeTypes::Charisma => self.chrm,
-            };
-            roll(att, roll_type)
+            }
+        }
+
+        // Set a single attribute, kept within the limits
+        pub fn set(&mut self, att_type: AttributeTypes, value: i8) {
+            let value = value.clamp(ATTRIBUTE_MIN, ATTRIBUTE_MAX);
+            match att_type {
+                AttributeTypes::Strength => self.stre = value,
+                AttributeTypes::Constitution => self.cons = value,
+                AttributeTypes::Dexterity => self.dext = value,
+                AttributeTypes::Intelligence => self.inte = value,
+                AttributeTypes::Wisdom => self.wisd = value,
+                AttributeTypes::Charisma => self.chrm = value,
+            }
+        }
+
+        // Raise or lower a single attribute
+        pub fn add(&mut self, att_type: AttributeTypes, change: i8) {
+            self.set(att_type, self.get(att_type).saturating_add(change));
+        }
+
+        // Implement a Challange Roll
+        pub fn challange_roll(
+            &self,
+            att_type: AttributeTypes,
+            roll_type: RollType,
+        ) -> (i32, RollResult) {
+            roll(self.get(att_type), roll_type)
         }
     }
 }
 
 // Random name generator
 pub mod rnd_name {
-    use rand::distributions::{Distribution, Uniform};
-    // use rand::ThreadRng
+    use std::{
+        collections::HashSet,
+        env,
+        error::Error,
+        fmt, fs, io,
+        path::{Path, PathBuf},
+        rc::Rc,
+    };
+
+    use rand::distributions::{Distribution, WeightedIndex};
+    use rand::rngs::ThreadRng;
+    use rand::seq::SliceRandom;
+
+    use super::rng_eng::rand_index;
+
+    // Nations players come from
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Nationality {
+        Irish,
+        English,
+        French,
+        Scottish,
+        Welsh,
+        SouthAfrican,
+        NewZealander,
+        Australian,
+    }
+
+    pub const NATIONALITIES: [Nationality; 8] = [
+        Nationality::Irish,
+        Nationality::English,
+        Nationality::French,
+        Nationality::Scottish,
+        Nationality::Welsh,
+        Nationality::SouthAfrican,
+        Nationality::NewZealander,
+        Nationality::Australian,
+    ];
+
+    impl Nationality {
+        // Short name used for the built in packs
+        pub fn key(&self) -> &'static str {
+            match *self {
+                Nationality::Irish => "irish",
+                Nationality::English => "english",
+                Nationality::French => "french",
+                Nationality::Scottish => "scottish",
+                Nationality::Welsh => "welsh",
+                Nationality::SouthAfrican => "sa",
+                Nationality::NewZealander => "nz",
+                Nationality::Australian => "aus",
+            }
+        }
+
+        // Nationality from its name or short name, in any case
+        pub fn from_name(name: &str) -> Option<Nationality> {
+            NATIONALITIES
+                .into_iter()
+                .find(|x| x.key().eq_ignore_ascii_case(name) || x.name().eq_ignore_ascii_case(name))
+        }
+
+        pub fn name(&self) -> &'static str {
+            match *self {
+                Nationality::Irish => "Irish",
+                Nationality::English => "English",
+                Nationality::French => "French",
+                Nationality::Scottish => "Scottish",
+                Nationality::Welsh => "Welsh",
+                Nationality::SouthAfrican => "South African",
+                Nationality::NewZealander => "New Zealander",
+                Nationality::Australian => "Australian",
+            }
+        }
+    }
+
+    // Pick a nationality from a weighted mix, Irish if the mix is empty
+    pub fn pick_nationality(mix: &[(Nationality, u32)]) -> Nationality {
+        let total = mix.iter().map(|x| x.1 as usize).sum::<usize>();
+        if total == 0 {
+            return Nationality::Irish;
+        }
+        let mut pick = rand_index(total);
+        for &(nationality, weight) in mix.iter() {
+            if pick < weight as usize {
+                return nationality;
+            }
+            pick -= weight as usize;
+        }
+        Nationality::Irish
+    }
+
+    // Name pack files
+    // A pack is a `.names` file with an optional header and a section of first and last names
+    //
+    //     nationality = French
+    //     weight = 2
+    //     [first]
+    //     Xabi 3
+    //     Imanol
+    //     [last]
+    //     Etxeberria
+    //
+    // Names can be followed by a weight, the default is one, and `#` starts a comment
+    // Packs for the same nation are picked by weight, a pack named after a nation replaces the
+    // built in one
+    const PACK_EXTENSION: &str = "names";
+    // Random draws before looking for a free name more carefully
+    const UNIQUE_ATTEMPTS: usize = 20;
+    const DEFAULT_NAME: &str = "Joe Bloggs";
+    // Directory or file to load packs from when building a generator from the environment
+    pub const NAME_PACKS_VAR: &str = "RUGBY_NAME_PACKS";
+
+    // Reason a name pack could not be loaded
+    #[derive(Debug)]
+    pub enum NamePackError {
+        Io(String, io::Error),
+        UnknownSetting(String, usize),
+        UnknownNationality(String, String),
+        BadWeight(String, usize),
+        NameOutsideSection(String, usize),
+        NoNames(String, &'static str), // Pack missing its first or last names
+    }
+
+    impl fmt::Display for NamePackError {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                NamePackError::Io(path, err) => write!(f, "{}: {}", path, err),
+                NamePackError::UnknownSetting(path, line) => {
+                    write!(f, "{}:{}: unknown setting", path, line)
+                }
+                NamePackError::UnknownNationality(path, name) => {
+                    write!(f, "{}: unknown nationality {}", path, name)
+                }
+                NamePackError::BadWeight(path, line) => {
+                    write!(f, "{}:{}: weight must be a positive number", path, line)
+                }
+                NamePackError::NameOutsideSection(path, line) => {
+                    write!(f, "{}:{}: name before [first] or [last]", path, line)
+                }
+                NamePackError::NoNames(path, section) => {
+                    write!(f, "{}: no {} names", path, section)
+                }
+            }
+        }
+    }
+
+    impl Error for NamePackError {}
+
+    // Names and weights for a nation, or a region of one
+    #[derive(Clone)]
+    struct NamePack {
+        name: String,
+        nationality: Nationality,
+        weight: u32,
+        first_names: Vec<String>,
+        first_weights: WeightedIndex<u32>,
+        second_names: Vec<String>,
+        second_weights: WeightedIndex<u32>,
+    }
+
+    impl NamePack {
+        // Pack from lists of names and weights, both lists need a name
+        fn new(
+            name: &str,
+            path: &str,
+            nationality: Nationality,
+            weight: u32,
+            first: Vec<(String, u32)>,
+            second: Vec<(String, u32)>,
+        ) -> Result<NamePack, NamePackError> {
+            let (first_names, first_weights) =
+                weighted(first).ok_or_else(|| NamePackError::NoNames(path.to_string(), "first"))?;
+            let (second_names, second_weights) =
+                weighted(second).ok_or_else(|| NamePackError::NoNames(path.to_string(), "last"))?;
+            Ok(NamePack {
+                name: name.to_string(),
+                nationality,
+                weight,
+                first_names,
+                first_weights,
+                second_names,
+                second_weights,
+            })
+        }
+
+        // Built in pack, one name on each line
+        fn built_in(nationality: Nationality, first: &str, second: &str) -> NamePack {
+            let names = |text: &str| {
+                text.split_whitespace()
+                    .map(|x| (x.to_string(), 1))
+                    .collect::<Vec<(String, u32)>>()
+            };
+            let key = nationality.key();
+            NamePack::new(key, key, nationality, 1, names(first), names(second)).unwrap()
+        }
+
+        // Read a pack file, the pack is named after the file
+        fn parse(name: &str, text: &str, path: &str) -> Result<NamePack, NamePackError> {
+            let mut nationality = Nationality::from_name(name);
+            let mut weight = 1;
+            let mut first = Vec::new();
+            let mut second = Vec::new();
+            let mut in_first = None;
+
+            for (i, line) in text.lines().enumerate() {
+                let line = line.split('#').next().unwrap_or("").trim();
+                let n = i + 1;
+                match line {
+                    "" => {}
+                    "[first]" => in_first = Some(true),
+                    "[last]" => in_first = Some(false),
+                    _ if line.contains('=') => {
+                        let (key, value) = line.split_once('=').unwrap();
+                        let value = value.trim();
+                        match key.trim() {
+                            "nationality" => {
+                                nationality =
+                                    Some(Nationality::from_name(value).ok_or_else(|| {
+                                        NamePackError::UnknownNationality(
+                                            path.to_string(),
+                                            value.to_string(),
+                                        )
+                                    })?)
+                            }
+                            "weight" => {
+                                weight = parse_weight(value)
+                                    .ok_or_else(|| NamePackError::BadWeight(path.to_string(), n))?
+                            }
+                            _ => return Err(NamePackError::UnknownSetting(path.to_string(), n)),
+                        }
+                    }
+                    _ => {
+                        let entry = parse_name(line)
+                            .ok_or_else(|| NamePackError::BadWeight(path.to_string(), n))?;
+                        match in_first {
+                            Some(true) => first.push(entry),
+                            Some(false) => second.push(entry),
+                            None => {
+                                return Err(NamePackError::NameOutsideSection(path.to_string(), n))
+                            }
+                        }
+                    }
+                }
+            }
+
+            let nationality = nationality.ok_or_else(|| {
+                NamePackError::UnknownNationality(path.to_string(), name.to_string())
+            })?;
+            NamePack::new(name, path, nationality, weight, first, second)
+        }
+
+        fn get_name(&self, rng: &mut ThreadRng) -> String {
+            let first = &self.first_names[self.first_weights.sample(rng)];
+            let second = &self.second_names[self.second_weights.sample(rng)];
+            first.to_string() + " " + second
+        }
+    }
+
+    // Names and a distribution to sample them by weight, None when there are no names
+    fn weighted(names: Vec<(String, u32)>) -> Option<(Vec<String>, WeightedIndex<u32>)> {
+        let weights = WeightedIndex::new(names.iter().map(|x| x.1)).ok()?;
+        Some((names.into_iter().map(|x| x.0).collect(), weights))
+    }
+
+    fn parse_weight(text: &str) -> Option<u32> {
+        text.parse::<u32>().ok().filter(|&x| x > 0)
+    }
+
+    // Name with an optional weight after it, names can have spaces
+    fn parse_name(line: &str) -> Option<(String, u32)> {
+        match line.rsplit_once(char::is_whitespace) {
+            Some((name, last)) if last.chars().all(|x| x.is_ascii_digit()) => {
+                Some((name.trim().to_string(), parse_weight(last)?))
+            }
+            _ => Some((line.to_string(), 1)),
+        }
+    }
+
+    // Built in packs, one name on each line of the files
+    fn built_in_packs() -> Vec<NamePack> {
+        vec![
+            NamePack::built_in(
+                Nationality::Irish,
+                include_str!("./names/irish_first.in"),
+                include_str!("./names/irish_last.in"),
+            ),
+            NamePack::built_in(
+                Nationality::English,
+                include_str!("./names/english_first.in"),
+                include_str!("./names/english_last.in"),
+            ),
+            NamePack::built_in(
+                Nationality::French,
+                include_str!("./names/french_first.in"),
+                include_str!("./names/french_last.in"),
+            ),
+            NamePack::built_in(
+                Nationality::Scottish,
+                include_str!("./names/scottish_first.in"),
+                include_str!("./names/scottish_last.in"),
+            ),
+            NamePack::built_in(
+                Nationality::Welsh,
+                include_str!("./names/welsh_first.in"),
+                include_str!("./names/welsh_last.in"),
+            ),
+            NamePack::built_in(
+                Nationality::SouthAfrican,
+                include_str!("./names/sa_first.in"),
+                include_str!("./names/sa_last.in"),
+            ),
+            NamePack::built_in(
+                Nationality::NewZealander,
+                include_str!("./names/nz_first.in"),
+                include_str!("./names/nz_last.in"),
+            ),
+            NamePack::built_in(
+                Nationality::Australian,
+                include_str!("./names/aus_first.in"),
+                include_str!("./names/aus_last.in"),
+            ),
+        ]
+    }
+
+    thread_local! {
+        // Parsed once and shared by every generator
+        static BUILT_IN: Rc<Vec<NamePack>> = Rc::new(built_in_packs());
+    }
 
     // Struct to control the rnd name generator
+    // Keeps track of the names it has given out so they can be kept unique
     pub struct NameGenerator {
-        first_names_irish: Vec<String>,
-        // first_names_english : Vec<_>,
-        // first_names_french : Vec<_>,
-        // first_names_scottish : Vec<_>,
-        // first_names_welsh : Vec<_>,
-        // first_names_sa : Vec<_>,
-        // first_names_nz : Vec<_>,
-        // first_names_aus : Vec<_>,
-        second_names_irish: Vec<String>,
-        // second_names_english : Vec<_>,
-        // second_names_french : Vec<_>,
-        // second_names_scottish : Vec<_>,
-        // second_names_welsh : Vec<_>,
-        // second_names_sa : Vec<_>,
-        // second_names_nz : Vec<_>,
-        // second_names_aus : Vec<_>,
+        packs: Rc<Vec<NamePack>>,
+        used: HashSet<String>,
     }
 
     impl NameGenerator {
-        // New random name generator
+        // New random name generator with the built in packs
         pub fn new() -> NameGenerator {
             NameGenerator {
-                // Load in the file, split by white spaces and convert to strings
-                first_names_irish: include_str!("./names/irish_first.in")
-                    .split_whitespace()
-                    .map(String::from)
-                    .collect(),
-                second_names_irish: include_str!("./names/irish_last.in")
-                    .split_whitespace()
-                    .map(String::from)
+                packs: BUILT_IN.with(Rc::clone),
+                used: HashSet::new(),
+            }
+        }
+
+        // Built in packs along with those in a pack file or directory of them
+        pub fn load(path: &Path) -> Result<NameGenerator, NamePackError> {
+            let mut names = NameGenerator::new();
+            names.add_packs(path)?;
+            Ok(names)
+        }
+
+        // Packs from the directory or file named in the environment
+        // Falls back to the built in packs when it is not set or does not load
+        pub fn from_env() -> NameGenerator {
+            let Some(path) = env::var_os(NAME_PACKS_VAR) else {
+                return NameGenerator::new();
+            };
+            NameGenerator::load(Path::new(&path)).unwrap_or_else(|err| {
+                eprintln!("Using the built in names, {}", err);
+                NameGenerator::new()
+            })
+        }
+
+        // Add a pack file, or every pack file in a directory
+        // Nothing is added unless every pack loads
+        pub fn add_packs(&mut self, path: &Path) -> Result<usize, NamePackError> {
+            let io_err = |err| NamePackError::Io(path.display().to_string(), err);
+            let mut files = match path.is_dir() {
+                true => fs::read_dir(path)
+                    .map_err(io_err)?
+                    .filter_map(|x| x.ok().map(|x| x.path()))
+                    .filter(|x| x.extension().is_some_and(|x| x == PACK_EXTENSION))
+                    .collect::<Vec<PathBuf>>(),
+                false => vec![path.to_path_buf()],
+            };
+            files.sort();
+
+            let mut packs = Vec::new();
+            for file in files.iter() {
+                let shown = file.display().to_string();
+                let text = fs::read_to_string(file)
+                    .map_err(|err| NamePackError::Io(shown.clone(), err))?;
+                let name = file.file_stem().unwrap_or_default().to_string_lossy();
+                packs.push(NamePack::parse(&name, &text, &shown)?);
+            }
+
+            let added = packs.len();
+            let own = Rc::make_mut(&mut self.packs);
+            for pack in packs {
+                own.retain(|x| x.name != pack.name);
+                own.push(pack);
+            }
+            Ok(added)
+        }
+
+        // Packs for a nationality, Irish names are used for a nation without a pack
+        fn packs(&self, nationality: Nationality) -> Vec<&NamePack> {
+            let packs = self
+                .packs
+                .iter()
+                .filter(|x| x.nationality == nationality)
+                .collect::<Vec<&NamePack>>();
+            match packs.is_empty() {
+                true => self
+                    .packs
+                    .iter()
+                    .filter(|x| x.nationality == Nationality::Irish)
                     .collect(),
+                false => packs,
             }
         }
 
-        pub fn get_name(self) -> String {
+        // Random name for a player of a nationality, names can repeat
+        pub fn get_name(&self, nationality: Nationality) -> String {
             let mut rng = rand::thread_rng();
+            let packs = self.packs(nationality);
+            match WeightedIndex::new(packs.iter().map(|x| x.weight)) {
+                Ok(pick) => packs[pick.sample(&mut rng)].get_name(&mut rng),
+                Err(_) => DEFAULT_NAME.to_string(),
+            }
+        }
+
+        // Random name nobody else from this generator has
+        pub fn unique_name(&mut self, nationality: Nationality) -> String {
+            for _ in 0..UNIQUE_ATTEMPTS {
+                let name = self.get_name(nationality);
+                if self.used.insert(name.clone()) {
+                    return name;
+                }
+            }
+            let name = self.get_name(nationality);
+            self.claim(&name, nationality)
+        }
+
+        // Keep a name if it is free, or find the nearest free one
+        // Tries the same first name with different surnames, then a middle initial, then a number
+        pub fn claim(&mut self, name: &str, nationality: Nationality) -> String {
+            if self.used.insert(name.to_string()) {
+                return name.to_string();
+            }
+            let (first, last) = name.split_once(' ').unwrap_or((name, ""));
+
+            let mut surnames = self
+                .packs(nationality)
+                .iter()
+                .flat_map(|x| x.second_names.iter())
+                .map(|x| first.to_string() + " " + x)
+                .collect::<Vec<String>>();
+            surnames.shuffle(&mut rand::thread_rng());
+            let initials = ('A'..='Z').map(|x| format!("{} {}. {}", first, x, last));
+            let numbered = (2..).map(|x| format!("{} {}", name, x));
 
-            // Name lengths can be different...
-            let indx1 = Uniform::from(0..self.first_names_irish.len());
-            let indx2 = Uniform::from(0..self.second_names_irish.len());
+            let name = surnames
+                .into_iter()
+                .chain(initials)
+                .chain(numbered)
+                .find(|x| !self.used.contains(x))
+                .unwrap();
+            self.used.insert(name.clone());
+            name
+        }
+
+        // Mark a name as taken
+        pub fn reserve(&mut self, name: &str) {
+            self.used.insert(name.to_string());
+        }
+
+        // Let a name be given out again
+        pub fn release(&mut self, name: &str) {
+            self.used.remove(name);
+        }
+
+        // Forget every name given out
+        pub fn clear(&mut self) {
+            self.used.clear();
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        const PACK: &str = "nationality = French\nweight = 2\n\n[first]\nImanol 3\nXabi\n\n[last]\nEtxeberria # common\nDe La Rosa 2\n";
+
+        fn parse(text: &str) -> Result<NamePack, NamePackError> {
+            NamePack::parse("test", text, "test.names")
+        }
+
+        #[test]
+        fn parses_settings_names_and_weights() {
+            let pack = parse(PACK).unwrap();
+            assert_eq!(pack.nationality, Nationality::French);
+            assert_eq!(pack.weight, 2);
+            assert_eq!(pack.first_names, ["Imanol", "Xabi"]);
+            assert_eq!(pack.second_names, ["Etxeberria", "De La Rosa"]);
+        }
+
+        #[test]
+        fn nationality_can_come_from_the_file_name() {
+            let pack = NamePack::parse("welsh", "[first]\nRhys\n[last]\nJones\n", "welsh.names");
+            assert_eq!(pack.unwrap().nationality, Nationality::Welsh);
+        }
+
+        #[test]
+        fn shipped_packs_parse() {
+            let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/names/packs");
+            assert!(NameGenerator::load(&dir).is_ok());
+        }
+
+        #[test]
+        fn name_before_a_section_header() {
+            let err = parse("nationality = French\nImanol\n[last]\nEtxeberria\n");
+            assert!(matches!(err, Err(NamePackError::NameOutsideSection(_, 2))));
+        }
+
+        #[test]
+        fn unknown_nationality() {
+            let err = parse("nationality = Atlantis\n[first]\nImanol\n[last]\nEtxeberria\n");
+            assert!(matches!(err, Err(NamePackError::UnknownNationality(_, x)) if x == "Atlantis"));
+
+            // No setting and a file name that is not a nation
+            let err = parse("[first]\nImanol\n[last]\nEtxeberria\n");
+            assert!(matches!(err, Err(NamePackError::UnknownNationality(_, x)) if x == "test"));
+        }
+
+        #[test]
+        fn empty_sections() {
+            let err = parse("nationality = French\n[first]\n[last]\nEtxeberria\n");
+            assert!(matches!(err, Err(NamePackError::NoNames(_, "first"))));
+            let err = parse("nationality = French\n[first]\nImanol\n[last]\n");
+            assert!(matches!(err, Err(NamePackError::NoNames(_, "last"))));
+            let err = parse("nationality = French\n");
+            assert!(matches!(err, Err(NamePackError::NoNames(_, "first"))));
+        }
+
+        // Generator with a single French pack of one first and one last name
+        fn tiny_generator() -> NameGenerator {
+            let pack = parse("nationality = French\n[first]\nImanol\n[last]\nEtxeberria\n");
+            NameGenerator {
+                packs: Rc::new(vec![pack.unwrap()]),
+                used: HashSet::new(),
+            }
+        }
+
+        #[test]
+        fn unique_names_do_not_repeat() {
+            let mut names = NameGenerator::new();
+            let drawn = (0..500)
+                .map(|_| names.unique_name(Nationality::Irish))
+                .collect::<HashSet<String>>();
+            assert_eq!(drawn.len(), 500);
+        }
+
+        #[test]
+        fn claim_keeps_a_free_name_and_renames_a_taken_one() {
+            let mut names = NameGenerator::new();
+            assert_eq!(names.claim("Rhys Jones", Nationality::Welsh), "Rhys Jones");
+            let renamed = names.claim("Rhys Jones", Nationality::Welsh);
+            assert_ne!(renamed, "Rhys Jones");
+            assert!(renamed.starts_with("Rhys "));
+
+            names.release("Rhys Jones");
+            assert_eq!(names.claim("Rhys Jones", Nationality::Welsh), "Rhys Jones");
+        }
+
+        #[test]
+        fn exhausted_pack_falls_back_to_initials_then_numbers() {
+            let mut names = tiny_generator();
+            let drawn = (0..30)
+                .map(|_| names.unique_name(Nationality::French))
+                .collect::<Vec<String>>();
+            assert_eq!(drawn[0], "Imanol Etxeberria");
+            assert_eq!(drawn[1], "Imanol A. Etxeberria");
+            assert_eq!(drawn[26], "Imanol Z. Etxeberria");
+            assert_eq!(drawn[27], "Imanol Etxeberria 2");
+            assert_eq!(drawn.iter().collect::<HashSet<&String>>().len(), 30);
+        }
+
+        #[test]
+        fn nation_without_a_pack_falls_back() {
+            let mut names = tiny_generator();
+            assert_eq!(names.get_name(Nationality::Welsh), DEFAULT_NAME);
+            names.packs = BUILT_IN.with(Rc::clone);
+            assert_ne!(names.get_name(Nationality::Welsh), DEFAULT_NAME);
+        }
 
-            // Grab a random first and last name and concat the two
-            let rnd_first_name = self.first_names_irish[indx1.sample(&mut rng)].to_string();
-            let rnd_second_name = self.second_names_irish[indx2.sample(&mut rng)].to_string();
-            rnd_first_name + " " + &rnd_second_name
+        #[test]
+        fn bad_settings_and_weights() {
+            let err = parse("colour = blue\n");
+            assert!(matches!(err, Err(NamePackError::UnknownSetting(_, 1))));
+            let err = parse("nationality = French\nweight = 0\n");
+            assert!(matches!(err, Err(NamePackError::BadWeight(_, 2))));
+            let err = parse("nationality = French\n[first]\nImanol 0\n");
+            assert!(matches!(err, Err(NamePackError::BadWeight(_, 3))));
         }
     }
 }
@@ -0,0 +1,541 @@
+// Module to write up a match once it has been played
+
+pub mod report {
+    use std::{cmp::Reverse, fmt::Write};
+
+    use crate::{
+        match_engine::match_engine::{EventKind, Match, MatchEvent, Side},
+        player::player::{get_number, Position},
+        stats::stats::{event_points, MatchStats, PlayerStats},
+        team::team::{Team, MATCHDAY_SQUAD, STARTERS},
+    };
+
+    // Every player who takes the field starts from an average rating
+    const BASE_RATING: f32 = 6.;
+    const MIN_RATING: f32 = 1.;
+    const MAX_RATING: f32 = 10.;
+    // Rating for each contribution
+    const WIN_RATING: f32 = 0.5;
+    const CARRY_RATING: f32 = 0.05;
+    const METRE_RATING: f32 = 0.02;
+    const TACKLE_MADE_RATING: f32 = 0.1;
+    const TACKLE_MISSED_RATING: f32 = -0.3;
+    const TRY_RATING: f32 = 1.;
+    const KICK_MADE_RATING: f32 = 0.2;
+    const KICK_MISSED_RATING: f32 = -0.2;
+    const CRITICAL_SUCCESS_RATING: f32 = 0.4;
+    const CRITICAL_FAIL_RATING: f32 = -0.4;
+
+    // Rating for a player in the matchday squad
+    pub struct PlayerRating {
+        pub index: usize, // Squad index of the player
+        pub shirt: usize,
+        pub name: String,
+        pub position: Position, // Starting position, Sub for replacements
+        pub minutes: u32,
+        pub rating: Option<f32>, // Replacements who did not come on are not rated
+    }
+
+    // Score in the match, or a break in play
+    pub struct Moment {
+        pub minute: u32,
+        pub side: Option<Side>, // None for breaks in play
+        pub description: String,
+        pub home_score: i32,
+        pub away_score: i32,
+    }
+
+    // Report on a played match
+    pub struct MatchReport {
+        pub home: String,
+        pub away: String,
+        pub home_score: i32,
+        pub away_score: i32,
+        pub moments: Vec<Moment>,
+        pub stats: MatchStats,
+        pub home_ratings: Vec<PlayerRating>,
+        pub away_ratings: Vec<PlayerRating>,
+        pub player_of_the_match: (Side, usize), // Index into the side's ratings
+    }
+
+    impl MatchReport {
+        pub fn from_match(game: &Match) -> MatchReport {
+            let stats = MatchStats::from_match(game);
+            let full_time = game.events.last().map(|x| x.minute()).unwrap_or(0);
+
+            let mut report = MatchReport {
+                home: game.home.name.clone(),
+                away: game.away.name.clone(),
+                home_score: game.home.score,
+                away_score: game.away.score,
+                moments: moments(&game.events, game.home, game.away),
+                home_ratings: Vec::new(),
+                away_ratings: Vec::new(),
+                stats,
+                player_of_the_match: (Side::Home, 0),
+            };
+            for side in [Side::Home, Side::Away] {
+                let team = match side {
+                    Side::Home => &*game.home,
+                    Side::Away => &*game.away,
+                };
+                let margin = report.margin(side);
+                let ratings = matchday_squad(game.lineup(side))
+                    .into_iter()
+                    .map(|(i, shirt, position)| {
+                        let started = position != Position::Sub;
+                        let minutes = minutes_played(&game.events, side, i, started, full_time);
+                        PlayerRating {
+                            index: i,
+                            shirt,
+                            name: team.players[i].name.clone(),
+                            position,
+                            minutes,
+                            rating: (minutes > 0 || started)
+                                .then(|| rating(&report.stats.team(side).players[i], margin)),
+                        }
+                    })
+                    .collect();
+                match side {
+                    Side::Home => report.home_ratings = ratings,
+                    Side::Away => report.away_ratings = ratings,
+                }
+            }
+            report.player_of_the_match = report.pick_player_of_the_match();
+            report
+        }
+
+        pub fn name(&self, side: Side) -> &str {
+            match side {
+                Side::Home => &self.home,
+                Side::Away => &self.away,
+            }
+        }
+
+        pub fn ratings(&self, side: Side) -> &[PlayerRating] {
+            match side {
+                Side::Home => &self.home_ratings,
+                Side::Away => &self.away_ratings,
+            }
+        }
+
+        // Points for minus points against
+        pub fn margin(&self, side: Side) -> i32 {
+            match side {
+                Side::Home => self.home_score - self.away_score,
+                Side::Away => self.away_score - self.home_score,
+            }
+        }
+
+        pub fn player_of_the_match(&self) -> &PlayerRating {
+            let (side, i) = self.player_of_the_match;
+            &self.ratings(side)[i]
+        }
+
+        // Highest rated player, the winning side takes a tie
+        fn pick_player_of_the_match(&self) -> (Side, usize) {
+            let mut best = (Side::Home, 0, f32::MIN, i32::MIN);
+            for side in [Side::Home, Side::Away] {
+                for (i, player) in self.ratings(side).iter().enumerate() {
+                    let rating = player.rating.unwrap_or(f32::MIN);
+                    let margin = self.margin(side);
+                    if rating > best.2 || (rating == best.2 && margin > best.3) {
+                        best = (side, i, rating, margin);
+                    }
+                }
+            }
+            (best.0, best.1)
+        }
+
+        // Point scorers for a side, highest first
+        pub fn scorers(&self, side: Side) -> Vec<String> {
+            let ratings = self.ratings(side);
+            let mut scorers = self
+                .stats
+                .team(side)
+                .players
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| x.points > 0)
+                .collect::<Vec<(usize, &PlayerStats)>>();
+            scorers.sort_by_key(|x| Reverse(x.1.points));
+
+            scorers
+                .iter()
+                .map(|(i, x)| {
+                    let mut detail = Vec::new();
+                    if x.tries > 0 {
+                        detail.push(format!("{}T", x.tries));
+                    }
+                    if x.kicks_made > 0 {
+                        detail.push(format!(
+                            "{}/{} kicks",
+                            x.kicks_made,
+                            x.kicks_made + x.kicks_missed
+                        ));
+                    }
+                    let name = ratings
+                        .iter()
+                        .find(|x| x.index == *i)
+                        .map(|x| x.name.as_str())
+                        .unwrap_or_default();
+                    format!("{} {} ({})", name, x.points, detail.join(", "))
+                })
+                .collect()
+        }
+
+        // Side by side stats, label then home and away
+        pub fn stat_rows(&self) -> Vec<(&'static str, String, String)> {
+            let (home, away) = (&self.stats.home, &self.stats.away);
+            vec![
+                (
+                    "Possession",
+                    format!("{:.0}%", home.possession),
+                    format!("{:.0}%", away.possession),
+                ),
+                (
+                    "Territory",
+                    format!("{:.0}%", home.territory),
+                    format!("{:.0}%", away.territory),
+                ),
+                ("Tries", home.tries.to_string(), away.tries.to_string()),
+                (
+                    "Carries",
+                    home.carries().to_string(),
+                    away.carries().to_string(),
+                ),
+                (
+                    "Metres",
+                    format!("{:.0}", home.metres()),
+                    format!("{:.0}", away.metres()),
+                ),
+                (
+                    "Tackles",
+                    format!(
+                        "{}/{}",
+                        home.tackles_made(),
+                        home.tackles_made() + home.tackles_missed()
+                    ),
+                    format!(
+                        "{}/{}",
+                        away.tackles_made(),
+                        away.tackles_made() + away.tackles_missed()
+                    ),
+                ),
+                (
+                    "Line outs",
+                    format!(
+                        "{}/{}",
+                        home.line_outs_won,
+                        home.line_outs_won + home.line_outs_lost
+                    ),
+                    format!(
+                        "{}/{}",
+                        away.line_outs_won,
+                        away.line_outs_won + away.line_outs_lost
+                    ),
+                ),
+                (
+                    "Scrums",
+                    format!("{}/{}", home.scrums_won, home.scrums_won + home.scrums_lost),
+                    format!("{}/{}", away.scrums_won, away.scrums_won + away.scrums_lost),
+                ),
+                (
+                    "Turnovers won",
+                    home.turnovers_won.to_string(),
+                    away.turnovers_won.to_string(),
+                ),
+                (
+                    "Penalties conceded",
+                    home.penalties_conceded.to_string(),
+                    away.penalties_conceded.to_string(),
+                ),
+                (
+                    "Yellow cards",
+                    home.yellow_cards.to_string(),
+                    away.yellow_cards.to_string(),
+                ),
+                (
+                    "Kicks from hand",
+                    home.kicks_from_hand.to_string(),
+                    away.kicks_from_hand.to_string(),
+                ),
+            ]
+        }
+
+        // Report as Markdown
+        pub fn to_markdown(&self) -> String {
+            let mut out = String::new();
+            let _ = writeln!(
+                out,
+                "# {} {} - {} {}\n",
+                self.home, self.home_score, self.away_score, self.away
+            );
+
+            let _ = writeln!(out, "## Scorers\n");
+            for side in [Side::Home, Side::Away] {
+                let _ = writeln!(
+                    out,
+                    "**{}**: {}",
+                    self.name(side),
+                    self.scorers(side).join("; ")
+                );
+                let _ = writeln!(out);
+            }
+
+            let _ = writeln!(out, "## Key moments\n");
+            for moment in self.moments.iter() {
+                let _ = writeln!(
+                    out,
+                    "- {}' {}{} {}-{}",
+                    moment.minute,
+                    moment.description,
+                    moment
+                        .side
+                        .map(|x| format!(" ({})", self.name(x)))
+                        .unwrap_or_default(),
+                    moment.home_score,
+                    moment.away_score
+                );
+            }
+
+            let _ = writeln!(out, "\n## Stats\n");
+            let _ = writeln!(out, "| | {} | {} |", self.home, self.away);
+            let _ = writeln!(out, "|---|---:|---:|");
+            for (label, home, away) in self.stat_rows() {
+                let _ = writeln!(out, "| {} | {} | {} |", label, home, away);
+            }
+
+            for side in [Side::Home, Side::Away] {
+                let _ = writeln!(out, "\n## {} ratings\n", self.name(side));
+                let _ = writeln!(out, "| # | Player | Position | Mins | Rating |");
+                let _ = writeln!(out, "|---:|---|---|---:|---:|");
+                for player in self.ratings(side) {
+                    let _ = writeln!(
+                        out,
+                        "| {} | {} | {:?} | {} | {} |",
+                        player.shirt,
+                        player.name,
+                        player.position,
+                        player.minutes,
+                        rating_text(player.rating)
+                    );
+                }
+            }
+
+            let potm = self.player_of_the_match();
+            let _ = writeln!(
+                out,
+                "\n**Player of the match**: {} ({})",
+                potm.name,
+                self.name(self.player_of_the_match.0)
+            );
+            out
+        }
+
+        // Report as plain text
+        pub fn to_text(&self) -> String {
+            let mut out = String::new();
+            let title = format!(
+                "{} {} - {} {}",
+                self.home, self.home_score, self.away_score, self.away
+            );
+            let _ = writeln!(out, "{}\n{}\n", title, "=".repeat(title.len()));
+
+            let _ = writeln!(out, "Scorers");
+            for side in [Side::Home, Side::Away] {
+                let _ = writeln!(
+                    out,
+                    "  {}: {}",
+                    self.name(side),
+                    self.scorers(side).join("; ")
+                );
+            }
+
+            let _ = writeln!(out, "\nKey moments");
+            for moment in self.moments.iter() {
+                let _ = writeln!(
+                    out,
+                    "  {:>2}' {:<30} {:<25} {:>3}-{}",
+                    moment.minute,
+                    moment.description,
+                    moment.side.map(|x| self.name(x)).unwrap_or_default(),
+                    moment.home_score,
+                    moment.away_score
+                );
+            }
+
+            let _ = writeln!(out, "\nStats");
+            for (label, home, away) in self.stat_rows() {
+                let _ = writeln!(out, "  {:<20} {:>8} {:>8}", label, home, away);
+            }
+
+            for side in [Side::Home, Side::Away] {
+                let _ = writeln!(out, "\n{} ratings", self.name(side));
+                for player in self.ratings(side) {
+                    let _ = writeln!(
+                        out,
+                        "  {:>2} {:<25} {:<17} {:>3}' {:>4}",
+                        player.shirt,
+                        player.name,
+                        format!("{:?}", player.position),
+                        player.minutes,
+                        rating_text(player.rating)
+                    );
+                }
+            }
+
+            let potm = self.player_of_the_match();
+            let _ = writeln!(
+                out,
+                "\nPlayer of the match: {} ({})",
+                potm.name,
+                self.name(self.player_of_the_match.0)
+            );
+            out
+        }
+    }
+
+    // Scores and breaks in play with the running score
+    fn moments(events: &[MatchEvent], home: &Team, away: &Team) -> Vec<Moment> {
+        let (mut home_score, mut away_score) = (0, 0);
+        let mut moments = Vec::new();
+        for event in events {
+            let points = event_points(&event.kind);
+            match event.side {
+                Side::Home => home_score += points,
+                Side::Away => away_score += points,
+            }
+
+            let team = match event.side {
+                Side::Home => home,
+                Side::Away => away,
+            };
+            let name = |i: usize| team.players[i].name.clone();
+            let description = match event.kind {
+                EventKind::Try(scorer) => format!("Try, {}", name(scorer)),
+                EventKind::PenaltyTry => "Penalty try".to_string(),
+                EventKind::Conversion(kicker, true) => format!("Conversion, {}", name(kicker)),
+                EventKind::PenaltyGoal(kicker, true) => format!("Penalty, {}", name(kicker)),
+                EventKind::DropGoal(kicker, true) => format!("Drop goal, {}", name(kicker)),
+                EventKind::YellowCard(player) => format!("Yellow card, {}", name(player)),
+                EventKind::HalfTime => "Half time".to_string(),
+                EventKind::ExtraTime => "Extra time".to_string(),
+                EventKind::SuddenDeath => "Sudden death".to_string(),
+                _ => continue,
+            };
+            moments.push(Moment {
+                minute: event.minute(),
+                side: (event_points(&event.kind) > 0).then_some(event.side),
+                description,
+                home_score,
+                away_score,
+            });
+        }
+        moments
+    }
+
+    // Squad index, shirt number and starting position of everyone named for a match
+    // Starters wear the number of their position, replacements follow on from the starters
+    fn matchday_squad(lineup: &[(Position, bool)]) -> Vec<(usize, usize, Position)> {
+        let mut starters = lineup
+            .iter()
+            .enumerate()
+            .filter(|(_, (pos, selected))| *selected && *pos != Position::Sub)
+            .map(|(i, (pos, _))| (i, get_number(*pos) as usize, *pos))
+            .collect::<Vec<_>>();
+        starters.sort_by_key(|x| x.1);
+        let replacements = lineup
+            .iter()
+            .enumerate()
+            .filter(|(_, (pos, selected))| *selected && *pos == Position::Sub)
+            .take(MATCHDAY_SQUAD.saturating_sub(starters.len()))
+            .enumerate()
+            .map(|(k, (i, _))| (i, STARTERS + k + 1, Position::Sub));
+        starters.extend(replacements);
+        starters
+    }
+
+    // Minutes on the pitch for a player in the matchday squad
+    fn minutes_played(
+        events: &[MatchEvent],
+        side: Side,
+        player: usize,
+        started: bool,
+        full_time: u32,
+    ) -> u32 {
+        let mut on = match started {
+            true => Some(0),
+            false => None,
+        };
+        for event in events.iter().filter(|x| x.side == side) {
+            match event.kind {
+                EventKind::Substitution(_, i) if i == player => on = Some(event.minute()),
+                EventKind::Substitution(i, _) if i == player => {
+                    return on.map(|x| event.minute() - x).unwrap_or(0)
+                }
+                _ => {}
+            }
+        }
+        on.map(|x| full_time - x).unwrap_or(0)
+    }
+
+    // Rating out of ten from a player's contributions and the result
+    fn rating(stats: &PlayerStats, margin: i32) -> f32 {
+        let result = match margin {
+            m if m > 0 => WIN_RATING,
+            m if m < 0 => -WIN_RATING,
+            _ => 0.,
+        };
+        let rating = BASE_RATING
+            + result
+            + stats.carries as f32 * CARRY_RATING
+            + stats.metres * METRE_RATING
+            + stats.tackles_made as f32 * TACKLE_MADE_RATING
+            + stats.tackles_missed as f32 * TACKLE_MISSED_RATING
+            + stats.tries as f32 * TRY_RATING
+            + stats.kicks_made as f32 * KICK_MADE_RATING
+            + stats.kicks_missed as f32 * KICK_MISSED_RATING
+            + stats.critical_successes as f32 * CRITICAL_SUCCESS_RATING
+            + stats.critical_fails as f32 * CRITICAL_FAIL_RATING;
+        // Round to the nearest half point
+        ((rating * 2.).round() / 2.).clamp(MIN_RATING, MAX_RATING)
+    }
+
+    fn rating_text(rating: Option<f32>) -> String {
+        rating
+            .map(|x| format!("{:.1}", x))
+            .unwrap_or("-".to_string())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::random_engine::rng_eng::RollResult;
+
+        #[test]
+        fn scorer_named_from_their_squad_index() {
+            let mut home = Team::new();
+            let mut away = Team::new();
+            // Loose head and full back swap, so the lineup is not in index order
+            home.players[0].selected_position = Position::FullBack;
+            home.players[14].selected_position = Position::LooseHead;
+            let scorer = home.players[14].name.clone();
+
+            let mut game = Match::new(&mut home, &mut away);
+            for kind in [EventKind::Try(14), EventKind::FullTime] {
+                game.events.push(MatchEvent {
+                    clock: 60,
+                    side: Side::Home,
+                    kind,
+                    roll: RollResult::Flat,
+                    pos: game.pos,
+                });
+            }
+            let report = MatchReport::from_match(&game);
+            let scorers = report.scorers(Side::Home);
+            assert_eq!(scorers.len(), 1);
+            assert!(scorers[0].starts_with(&scorer), "{}", scorers[0]);
+        }
+    }
+}
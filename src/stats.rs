@@ -0,0 +1,305 @@
+// Module to build the stat sheet from a match log
+
+pub mod stats {
+    use crate::{
+        events::events::{
+            BreakdownResult, LineOutResult, MaulResult, PassResult, PhaseResult, ScrumResult,
+        },
+        match_engine::match_engine::{
+            EventKind, Match, MatchEvent, Side, CONVERSION_POINTS, DROP_GOAL_POINTS,
+            PENALTY_POINTS, PENALTY_TRY_POINTS, TRY_POINTS,
+        },
+        random_engine::rng_eng::RollResult,
+    };
+
+    // Individual stats
+    #[derive(Clone, Default)]
+    pub struct PlayerStats {
+        pub carries: u32,
+        pub metres: f32,
+        pub tackles_made: u32,
+        pub tackles_missed: u32,
+        pub tries: u32,
+        pub points: i32,
+        pub kicks_made: u32, // Kicks at goal
+        pub kicks_missed: u32,
+        pub critical_successes: u32, // Critical rolls in carries and tackles
+        pub critical_fails: u32,
+        pub yellow_cards: u32,
+    }
+
+    // Team stats
+    #[derive(Clone, Default)]
+    pub struct TeamStats {
+        pub possession: f32,           // Percentage of time with the ball
+        pub territory: f32,            // Percentage of time in the opposition half
+        pub players: Vec<PlayerStats>, // Indexed by squad position
+        pub line_outs_won: u32,        // On our own throw
+        pub line_outs_lost: u32,
+        pub scrums_won: u32, // On our own feed
+        pub scrums_lost: u32,
+        pub penalties_conceded: u32,
+        pub yellow_cards: u32,
+        pub turnovers_won: u32,
+        pub kicks_from_hand: u32,
+        pub tries: u32,
+        pub conversions: u32,
+        pub penalty_goals: u32,
+        pub drop_goals: u32,
+        pub penalty_tries: u32,
+    }
+
+    impl TeamStats {
+        fn new(squad: usize) -> TeamStats {
+            TeamStats {
+                players: vec![PlayerStats::default(); squad],
+                ..TeamStats::default()
+            }
+        }
+
+        pub fn carries(&self) -> u32 {
+            self.players.iter().map(|x| x.carries).sum()
+        }
+
+        pub fn metres(&self) -> f32 {
+            self.players.iter().map(|x| x.metres).sum()
+        }
+
+        pub fn tackles_made(&self) -> u32 {
+            self.players.iter().map(|x| x.tackles_made).sum()
+        }
+
+        pub fn tackles_missed(&self) -> u32 {
+            self.players.iter().map(|x| x.tackles_missed).sum()
+        }
+
+        // Points breakdown
+        pub fn points(&self) -> i32 {
+            self.tries as i32 * TRY_POINTS
+                + self.conversions as i32 * CONVERSION_POINTS
+                + self.penalty_goals as i32 * PENALTY_POINTS
+                + self.drop_goals as i32 * DROP_GOAL_POINTS
+                + self.penalty_tries as i32 * PENALTY_TRY_POINTS
+        }
+    }
+
+    // Stats for both teams
+    pub struct MatchStats {
+        pub home: TeamStats,
+        pub away: TeamStats,
+    }
+
+    impl MatchStats {
+        // Stats from a played match
+        pub fn from_match(game: &Match) -> MatchStats {
+            MatchStats::from_events(
+                &game.events,
+                game.home.players.len(),
+                game.away.players.len(),
+            )
+        }
+
+        // Stats from a match log, given the size of each squad
+        pub fn from_events(
+            events: &[MatchEvent],
+            home_squad: usize,
+            away_squad: usize,
+        ) -> MatchStats {
+            let mut stats = MatchStats {
+                home: TeamStats::new(home_squad),
+                away: TeamStats::new(away_squad),
+            };
+
+            // Carries and tackles share the roll of the phase they were made in
+            let mut phase_roll = RollResult::Flat;
+            for event in events {
+                if let EventKind::Phase(_) = event.kind {
+                    phase_roll = event.roll;
+                }
+                stats.add_event(event);
+                stats.add_roll(event, phase_roll);
+            }
+            stats.add_time(events);
+            stats
+        }
+
+        pub fn team(&self, side: Side) -> &TeamStats {
+            match side {
+                Side::Home => &self.home,
+                Side::Away => &self.away,
+            }
+        }
+
+        fn team_mut(&mut self, side: Side) -> &mut TeamStats {
+            match side {
+                Side::Home => &mut self.home,
+                Side::Away => &mut self.away,
+            }
+        }
+
+        // Count a single event towards the team it belongs to, or the opposition
+        fn add_event(&mut self, event: &MatchEvent) {
+            let side = event.side;
+            let team = self.team_mut(side);
+            match event.kind {
+                EventKind::Carry(player, metres) => {
+                    team.players[player].carries += 1;
+                    team.players[player].metres += metres;
+                }
+                EventKind::Tackle(player, true) => team.players[player].tackles_made += 1,
+                EventKind::Tackle(player, false) => team.players[player].tackles_missed += 1,
+                EventKind::LineOut(LineOutResult::CleanCatch | LineOutResult::Maul) => {
+                    team.line_outs_won += 1
+                }
+                EventKind::LineOut(LineOutResult::Stolen | LineOutResult::NotStraight) => {
+                    team.line_outs_lost += 1
+                }
+                EventKind::Scrum(res) => {
+                    match res {
+                        ScrumResult::AttackBall
+                        | ScrumResult::Number8PickUp
+                        | ScrumResult::PenaltyAttack
+                        | ScrumResult::FreeKickAttack => team.scrums_won += 1,
+                        _ => team.scrums_lost += 1,
+                    }
+                    match res {
+                        ScrumResult::PenaltyAttack => {
+                            self.team_mut(side.other()).penalties_conceded += 1
+                        }
+                        ScrumResult::PenaltyDefence => team.penalties_conceded += 1,
+                        _ => {}
+                    }
+                }
+                EventKind::Breakdown(BreakdownResult::Turnover)
+                | EventKind::Pass(PassResult::Intercept)
+                | EventKind::Phase(PhaseResult::Handling(PassResult::Intercept)) => {
+                    self.team_mut(side.other()).turnovers_won += 1
+                }
+                EventKind::Breakdown(BreakdownResult::PenaltyAttack)
+                | EventKind::Maul(MaulResult::Penalty | MaulResult::PenaltyTry) => {
+                    self.team_mut(side.other()).penalties_conceded += 1
+                }
+                EventKind::Breakdown(BreakdownResult::PenaltyDefence) => {
+                    team.penalties_conceded += 1
+                }
+                EventKind::TerritoryKick(_) => team.kicks_from_hand += 1,
+                EventKind::Try(player) => {
+                    team.tries += 1;
+                    team.players[player].tries += 1;
+                    team.players[player].points += TRY_POINTS;
+                }
+                EventKind::Conversion(player, true) => {
+                    team.conversions += 1;
+                    team.players[player].points += CONVERSION_POINTS;
+                    team.players[player].kicks_made += 1;
+                }
+                EventKind::PenaltyGoal(player, true) => {
+                    team.penalty_goals += 1;
+                    team.players[player].points += PENALTY_POINTS;
+                    team.players[player].kicks_made += 1;
+                }
+                EventKind::DropGoal(player, true) => {
+                    team.drop_goals += 1;
+                    team.players[player].points += DROP_GOAL_POINTS;
+                    team.players[player].kicks_made += 1;
+                }
+                EventKind::Conversion(player, false)
+                | EventKind::PenaltyGoal(player, false)
+                | EventKind::DropGoal(player, false) => team.players[player].kicks_missed += 1,
+                EventKind::PenaltyTry => team.penalty_tries += 1,
+                EventKind::YellowCard(player) => {
+                    team.yellow_cards += 1;
+                    team.players[player].yellow_cards += 1;
+                }
+                _ => {}
+            }
+        }
+
+        // Critical rolls for the players involved in a phase
+        // The phase roll is from the attack's point of view
+        fn add_roll(&mut self, event: &MatchEvent, phase_roll: RollResult) {
+            let (player, roll) = match (event.kind, phase_roll) {
+                (EventKind::Carry(player, _), roll) => (player, roll),
+                (EventKind::Tackle(player, true), RollResult::CriticalFail) => {
+                    (player, RollResult::CriticalSuccess)
+                }
+                (EventKind::Tackle(player, false), RollResult::CriticalSuccess) => {
+                    (player, RollResult::CriticalFail)
+                }
+                _ => return,
+            };
+            let player = &mut self.team_mut(event.side).players[player];
+            match roll {
+                RollResult::CriticalSuccess => player.critical_successes += 1,
+                RollResult::CriticalFail => player.critical_fails += 1,
+                RollResult::Flat => {}
+            }
+        }
+
+        // Possession and territory from the time between events
+        fn add_time(&mut self, events: &[MatchEvent]) {
+            // Only events for the side with the ball move the clock on
+            let timed = events
+                .iter()
+                .filter(|x| {
+                    !matches!(
+                        x.kind,
+                        EventKind::Tackle(..)
+                            | EventKind::Substitution(..)
+                            | EventKind::YellowCard(_)
+                            | EventKind::HalfTime
+                            | EventKind::FullTime
+                            | EventKind::ExtraTime
+                            | EventKind::SuddenDeath
+                            | EventKind::KickingCompetition(..)
+                    )
+                })
+                .collect::<Vec<&MatchEvent>>();
+
+            let (mut home_ball, mut away_ball) = (0., 0.);
+            let (mut home_half, mut away_half) = (0., 0.);
+            for pair in timed.windows(2) {
+                let time = (pair[1].clock - pair[0].clock) as f32;
+                // Restarts belong to the kicking side, the receiving side have the ball
+                let possession = match pair[0].kind {
+                    EventKind::KickOff | EventKind::DropOut => pair[0].side.other(),
+                    _ => pair[0].side,
+                };
+                match possession {
+                    Side::Home => home_ball += time,
+                    Side::Away => away_ball += time,
+                }
+                // Ball in the away half is home territory and vice versa
+                if pair[0].pos.in_own_half(&false) {
+                    home_half += time;
+                } else if pair[0].pos.in_own_half(&true) {
+                    away_half += time;
+                }
+            }
+
+            self.home.possession = percentage(home_ball, away_ball);
+            self.away.possession = percentage(away_ball, home_ball);
+            self.home.territory = percentage(home_half, away_half);
+            self.away.territory = percentage(away_half, home_half);
+        }
+    }
+
+    // Points scored by a single event
+    pub fn event_points(kind: &EventKind) -> i32 {
+        match kind {
+            EventKind::Try(_) => TRY_POINTS,
+            EventKind::Conversion(_, true) => CONVERSION_POINTS,
+            EventKind::PenaltyGoal(_, true) => PENALTY_POINTS,
+            EventKind::DropGoal(_, true) => DROP_GOAL_POINTS,
+            EventKind::PenaltyTry => PENALTY_TRY_POINTS,
+            _ => 0,
+        }
+    }
+
+    fn percentage(part: f32, other: f32) -> f32 {
+        match part + other > 0. {
+            true => 100. * part / (part + other),
+            false => 50.,
+        }
+    }
+}
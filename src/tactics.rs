@@ -0,0 +1,124 @@
+// Module to handle a team's game plan
+
+pub mod tactics {
+    use crate::{
+        coach::coach::{MatchState, PhaseOption},
+        events::events::{LineOutCall, ScrumCall, BREAKDOWN_NUMBERS},
+    };
+
+    // Shape modifiers for the defensive line speed
+    const RUSH_SHAPE_MOD: i32 = 6;
+    const DRIFT_SHAPE_MOD: i32 = -3;
+    // Shape modifiers for the width of attack
+    const WIDE_SHAPE_MOD: i32 = 4;
+    const NARROW_SHAPE_MOD: i32 = -4;
+    // Shape lost for every extra player committed to the breakdown
+    const RUCK_NUMBER_SHAPE: i32 = 3;
+
+    // Kicking or running game
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum GamePlan {
+        Kicking, // Kick for territory from anywhere in our own half
+        Balanced,
+        Running, // Keep ball in hand outside our own 22
+    }
+
+    // Speed of the defensive line
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum LineSpeed {
+        Drift, // Slide across, concede ground but scramble
+        Standard,
+        Rush, // Shut down the attack, exposed if beaten
+    }
+
+    impl LineSpeed {
+        // Defensive bonus against the attacking shape
+        pub fn shape_mod(&self) -> i32 {
+            match *self {
+                LineSpeed::Drift => DRIFT_SHAPE_MOD,
+                LineSpeed::Standard => 0,
+                LineSpeed::Rush => RUSH_SHAPE_MOD,
+            }
+        }
+
+        // Defenders who get a shot at the carrier before the line is broken
+        pub fn defenders_in_contact(&self) -> usize {
+            match *self {
+                LineSpeed::Drift => 4,
+                LineSpeed::Standard => 3,
+                LineSpeed::Rush => 1,
+            }
+        }
+    }
+
+    // Width of the attack
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum AttackWidth {
+        Narrow, // Forwards carry off short passes
+        Standard,
+        Wide, // Backs carry off long and skip passes
+    }
+
+    impl AttackWidth {
+        // Attacking bonus from stretching the defence
+        pub fn shape_mod(&self) -> i32 {
+            match *self {
+                AttackWidth::Narrow => NARROW_SHAPE_MOD,
+                AttackWidth::Standard => 0,
+                AttackWidth::Wide => WIDE_SHAPE_MOD,
+            }
+        }
+    }
+
+    // Team tactics
+    pub struct Tactics {
+        pub game_plan: GamePlan,
+        pub line_speed: LineSpeed,
+        pub ruck_numbers: usize,    // Support committed to our own rucks
+        pub contest_numbers: usize, // Support committed to the opposition rucks
+        pub width: AttackWidth,
+        pub line_out: LineOutCall, // Maul flag is only used inside the opposition 22
+        pub scrum_call: ScrumCall, // Call used inside the opposition 22
+    }
+
+    impl Tactics {
+        // Default balanced game plan
+        pub fn new() -> Tactics {
+            Tactics {
+                game_plan: GamePlan::Balanced,
+                line_speed: LineSpeed::Standard,
+                ruck_numbers: BREAKDOWN_NUMBERS,
+                contest_numbers: BREAKDOWN_NUMBERS,
+                width: AttackWidth::Standard,
+                line_out: LineOutCall {
+                    maul: true,
+                    ..LineOutCall::new()
+                },
+                scrum_call: ScrumCall::Standard,
+            }
+        }
+
+        // Shape lost in attack from extra players committed to our rucks
+        pub fn ruck_shape_mod(&self) -> i32 {
+            -RUCK_NUMBER_SHAPE * (self.ruck_numbers as i32 - BREAKDOWN_NUMBERS as i32)
+        }
+
+        // Shape lost in defence from extra players contesting their rucks
+        pub fn contest_shape_mod(&self) -> i32 {
+            -RUCK_NUMBER_SHAPE * (self.contest_numbers as i32 - BREAKDOWN_NUMBERS as i32)
+        }
+
+        // Apply the game plan to the coach's call with ball in hand
+        pub fn phase_option(&self, option: PhaseOption, state: &MatchState) -> PhaseOption {
+            match (option, self.game_plan) {
+                (PhaseOption::Run, GamePlan::Kicking) if state.pos.in_own_half(&state.is_home) => {
+                    PhaseOption::Kick
+                }
+                (PhaseOption::Kick, GamePlan::Running) if !state.pos.in_own_22(&state.is_home) => {
+                    PhaseOption::Run
+                }
+                _ => option,
+            }
+        }
+    }
+}
@@ -2,15 +2,97 @@ pub mod team {
     // use std::fmt; // Import `fmt`
 
     use crate::{
-        player::player::{get_position, Player, Position, FORWARDS},
-        random_engine::rng_eng::{AttributeTypes, RollResult, RollType},
+        coach::coach::{Conservative, DecisionPolicy},
+        finance::finance::Accounts,
+        player::player::{get_position, Player, Position, BACKS, FORWARDS},
+        random_engine::{
+            rnd_name::{pick_nationality, NameGenerator, Nationality},
+            rng_eng::AttributeTypes,
+        },
+        report::report::PlayerRating,
+        tactics::tactics::Tactics,
+        training::training::TrainingPlan,
+        transfer::transfer::{Prudent, TransferPolicy},
     };
 
+    // Transfer funds for a new club, in thousands
+    const STARTING_BUDGET: i64 = 1000;
+
+    // Nationality mix of a new club, mostly home grown
+    const DEFAULT_NATIONALITIES: [(Nationality, u32); 8] = [
+        (Nationality::Irish, 70),
+        (Nationality::English, 6),
+        (Nationality::French, 2),
+        (Nationality::Scottish, 3),
+        (Nationality::Welsh, 3),
+        (Nationality::SouthAfrican, 7),
+        (Nationality::NewZealander, 6),
+        (Nationality::Australian, 3),
+    ];
+
+    // Vice-captains backing up the captain
+    const LEADERSHIP_GROUP: usize = 3;
+    // Captain's Charisma and Wisdom for each point of team morale
+    const CAPTAIN_MORALE_DIV: i32 = 8;
+    // Match rating of a team in ordinary form, and the change in form worth a point of morale
+    // Results alone move ratings by half a point, it takes poor or fine play as well
+    const EXPECTED_FORM: f32 = 6.;
+    const FORM_PER_MORALE: f32 = 1.;
+    const MAX_FORM_MORALE: i32 = 1;
+    // Most morale and momentum can add to or take off a contest
+    const MAX_ROLL_MOD: i32 = 1;
+    // Backs who take the kicks when the fly half is off the pitch, in order
+    const KICKER_ORDER: [Position; 3] = [
+        Position::InsideCentre,
+        Position::FullBack,
+        Position::ScrumHalf,
+    ];
+
+    // Players who start a match
+    pub const STARTERS: usize = 15;
+
+    // Positions covered by the eight replacements on the bench
+    const BENCH: [&[Position]; 8] = [
+        &[Position::Hooker],
+        &[Position::LooseHead],
+        &[Position::TightHead],
+        &[Position::SecondRow1, Position::SecondRow2],
+        &[
+            Position::BlindSideFlanker,
+            Position::OpenSideFlanker,
+            Position::Number8,
+        ],
+        &[Position::ScrumHalf],
+        &[Position::FlyHalf, Position::InsideCentre],
+        &[
+            Position::OutsideCentre,
+            Position::LeftWinger,
+            Position::RightWinger,
+            Position::FullBack,
+        ],
+    ];
+
+    // Starters and replacements named for a match
+    pub const MATCHDAY_SQUAD: usize = STARTERS + BENCH.len();
+
+    // Selected position and selection of each player in the squad
+    pub type Lineup = Vec<(Position, bool)>;
+
     // Team struct
     pub struct Team {
         pub name: String,
         pub score: i32,
         pub players: Vec<Player>,
+        pub coach: Box<dyn DecisionPolicy>,
+        pub tactics: Tactics,
+        pub training: TrainingPlan,
+        pub manager: Box<dyn TransferPolicy>,
+        pub budget: i64,                            // Transfer funds, in thousands
+        pub accounts: Accounts,                     // Books for the season so far
+        pub nationalities: Vec<(Nationality, u32)>, // Weighted mix of new players
+        pub captain: usize,                         // Squad index of the captain
+        pub leaders: Vec<usize>,                    // Leadership group, in the order they take over
+        pub morale: i32,                            // Pre-match spirit, added once to each contest
     }
 
     impl Team {
@@ -22,20 +104,68 @@ pub mod team {
                 score: 0,
                 // Create and vector of random players
                 players: (0..26).map(|_| Player::new()).collect::<Vec<Player>>(),
+                coach: Box::new(Conservative),
+                tactics: Tactics::new(),
+                training: TrainingPlan::new(),
+                manager: Box::new(Prudent),
+                budget: STARTING_BUDGET,
+                accounts: Accounts::new(),
+                nationalities: DEFAULT_NATIONALITIES.to_vec(),
+                captain: 0,
+                leaders: Vec::new(),
+                morale: 0,
             };
+            // Names are unique within the squad
+            tmp.draw_nationalities(&mut NameGenerator::new());
 
             for i in 0..tmp.players.len() {
-                tmp.players[i]
-                    .position
-                    .push(get_position((i + 1).try_into().unwrap()));
-                if i < 15 {
+                // Replacements cover the bench positions, the rest of the squad is not selected
+                match i {
+                    _ if (STARTERS..MATCHDAY_SQUAD).contains(&i) => {
+                        tmp.players[i].position = BENCH[i - STARTERS].to_vec()
+                    }
+                    _ => tmp.players[i]
+                        .position
+                        .push(get_position((i + 1).try_into().unwrap())),
+                }
+                if i < STARTERS {
                     tmp.players[i].selected_position = get_position((i + 1).try_into().unwrap());
                 }
+                tmp.players[i].is_selected = i < MATCHDAY_SQUAD;
+                tmp.players[i].set_build();
             }
+            tmp.pick_leaders();
 
             tmp
         }
 
+        // Change the nationality mix and draw the squad from it again
+        pub fn set_nationalities(
+            &mut self,
+            mix: Vec<(Nationality, u32)>,
+            names: &mut NameGenerator,
+        ) {
+            self.nationalities = mix;
+            self.draw_nationalities(names);
+        }
+
+        // Give every player a nationality from the mix and a name to match
+        // Names given up by the squad can be used again
+        pub fn draw_nationalities(&mut self, names: &mut NameGenerator) {
+            for player in self.players.iter_mut() {
+                names.release(&player.name);
+                player.nationality = pick_nationality(&self.nationalities);
+                player.name = names.unique_name(player.nationality);
+            }
+        }
+
+        // Keep the squad's names, renaming anyone whose name is already taken
+        pub fn claim_names(&mut self, names: &mut NameGenerator) {
+            for player in self.players.iter_mut() {
+                player.name = names.claim(&player.name, player.nationality);
+            }
+        }
+
         // Get the player by position
         pub fn get_player(&self, pos: Position) -> &Player {
             let player = self
@@ -46,14 +176,297 @@ pub mod team {
             player
         }
 
+        // Player on the pitch doing the job of a position
+        // A sin binned player is covered by the next kicker, or by someone else in the same unit
+        pub fn active_player(&self, pos: Position) -> &Player {
+            let active = |p: &Position| {
+                self.players
+                    .iter()
+                    .find(|x| x.is_selected && x.selected_position == *p && !x.in_sin_bin())
+            };
+            let kickers: &[Position] = match pos {
+                Position::FlyHalf => &KICKER_ORDER,
+                _ => &[],
+            };
+            let unit: &[Position] = match FORWARDS.contains(&pos) {
+                true => &FORWARDS,
+                false => &BACKS,
+            };
+            [pos]
+                .iter()
+                .chain(kickers)
+                .chain(unit)
+                .chain(FORWARDS.iter().chain(BACKS.iter()))
+                .find_map(active)
+                .unwrap_or_else(|| self.get_player(pos))
+        }
+
+        // Get the selected players in the given positions
+        pub fn get_players(&self, pos: &[Position]) -> Vec<&Player> {
+            self.players
+                .iter()
+                .filter(|x| x.is_selected && pos.contains(&x.selected_position))
+                .collect()
+        }
+
+        // Index of a player in the squad
+        pub fn index_of(&self, player: &Player) -> usize {
+            self.players
+                .iter()
+                .position(|x| std::ptr::eq(x, player))
+                .unwrap()
+        }
+
+        // Replacements still available on the bench
+        pub fn bench(&self) -> Vec<usize> {
+            (0..self.players.len())
+                .filter(|&i| {
+                    self.players[i].is_selected
+                        && self.players[i].selected_position == Position::Sub
+                        && !self.players[i].position.contains(&Position::Sub)
+                })
+                .collect()
+        }
+
+        // Bring a replacement on for the starter in one of their positions
+        // The replaced player takes no further part, return their index
+        pub fn substitute(&mut self, on: usize) -> Option<usize> {
+            // The most tired player the replacement can cover comes off
+            // Players in the sin bin cannot be replaced
+            let off = (0..self.players.len())
+                .filter(|&i| {
+                    self.players[i].selected_position != Position::Sub
+                        && !self.players[i].in_sin_bin()
+                        && self.players[on]
+                            .position
+                            .contains(&self.players[i].selected_position)
+                })
+                .rev()
+                .max_by(|&a, &b| self.players[a].fatigue.total_cmp(&self.players[b].fatigue))?;
+
+            self.players[on].selected_position = self.players[off].selected_position;
+            self.players[off].selected_position = Position::Sub;
+            self.players[off].is_selected = false;
+            Some(off)
+        }
+
+        // Starters with the most Charisma and Wisdom lead the team
+        // The best of them is captain, the next few make up the leadership group
+        pub fn pick_leaders(&mut self) {
+            let leadership = |x: &Player| {
+                x.attributes.get(AttributeTypes::Charisma) as i32
+                    + x.attributes.get(AttributeTypes::Wisdom) as i32
+            };
+            let mut starters = self.on_field();
+            starters.sort_by_key(|&i| -leadership(&self.players[i]));
+            self.captain = starters.first().copied().unwrap_or(0);
+            self.leaders = starters
+                .into_iter()
+                .skip(1)
+                .take(LEADERSHIP_GROUP)
+                .collect();
+        }
+
+        // Player leading the team on the pitch
+        // The leadership group take over when the captain is off or in the sin bin
+        // Failing them, the most charismatic player left steps up
+        pub fn captain(&self) -> &Player {
+            let playing = self
+                .on_field()
+                .into_iter()
+                .filter(|&i| !self.players[i].in_sin_bin())
+                .collect::<Vec<usize>>();
+            let index = std::iter::once(self.captain)
+                .chain(self.leaders.iter().copied())
+                .find(|i| playing.contains(i))
+                .or_else(|| {
+                    playing
+                        .into_iter()
+                        .max_by_key(|&i| self.players[i].attributes.get(AttributeTypes::Charisma))
+                })
+                .unwrap_or(self.captain);
+            &self.players[index]
+        }
+
+        // Average form of the players on the pitch with recent matches
+        pub fn form(&self) -> Option<f32> {
+            let form = self
+                .on_field()
+                .into_iter()
+                .filter_map(|i| self.players[i].form())
+                .collect::<Vec<f32>>();
+            match form.is_empty() {
+                true => None,
+                false => Some(form.iter().sum::<f32>() / form.len() as f32),
+            }
+        }
+
+        // Morale for a match, from the team's form and how the captain inspires them
+        // A home crowd gives the team a lift on top
+        pub fn set_morale(&mut self, crowd: i32) {
+            let captain = self.captain();
+            let inspiration = captain.attributes.get(AttributeTypes::Charisma) as i32
+                + captain.attributes.get(AttributeTypes::Wisdom) as i32;
+            let form = self
+                .form()
+                .map(|x| ((x - EXPECTED_FORM) / FORM_PER_MORALE) as i32)
+                .unwrap_or(0)
+                .clamp(-MAX_FORM_MORALE, MAX_FORM_MORALE);
+            self.morale = inspiration / CAPTAIN_MORALE_DIV + form + crowd;
+            self.set_momentum(0);
+        }
+
+        // Swing of the match on top of the team's morale
+        pub fn set_momentum(&mut self, momentum: i32) {
+            let roll_mod = (self.morale + momentum).clamp(-MAX_ROLL_MOD, MAX_ROLL_MOD);
+            for player in self.players.iter_mut() {
+                player.morale = roll_mod;
+            }
+        }
+
+        // Morale and momentum added once to a team roll
+        pub fn roll_mod(&self) -> i32 {
+            self.players.first().map(|x| x.morale).unwrap_or(0)
+        }
+
+        // Send a player to the sin bin for a number of seconds
+        pub fn sin_bin(&mut self, player: usize, seconds: u32) {
+            self.players[player].sin_bin = seconds;
+        }
+
+        // Players on the pitch tire as the match goes on
+        // Anyone in the sin bin serves their time instead
+        pub fn tire(&mut self, seconds: u32) {
+            for i in self.on_field() {
+                match self.players[i].in_sin_bin() {
+                    true => {
+                        self.players[i].sin_bin = self.players[i].sin_bin.saturating_sub(seconds)
+                    }
+                    false => self.players[i].tire(seconds),
+                }
+            }
+        }
+
+        // Everyone starts a match fresh and with a clean record
+        pub fn rest(&mut self) {
+            for player in self.players.iter_mut() {
+                player.fatigue = 0.;
+                player.sin_bin = 0;
+            }
+        }
+
+        // Put a new player in a squad place, taking over their selection
+        // Returns the player who has been replaced
+        pub fn replace(&mut self, slot: usize, mut player: Player) -> Player {
+            player.selected_position = self.players[slot].selected_position;
+            player.is_selected = self.players[slot].is_selected;
+            std::mem::replace(&mut self.players[slot], player)
+        }
+
+        // Take an unavailable player out of the lineup
+        // A starter is covered from the bench first, then from outside the matchday squad
+        // Returns false when nobody can cover a starting position
+        pub fn withdraw(&mut self, out: usize) -> bool {
+            let pos = self.players[out].selected_position;
+            if !self.players[out].is_selected {
+                return true;
+            }
+
+            let bench = |x: &Player| {
+                x.is_selected && x.selected_position == Position::Sub && x.injury_weeks == 0
+            };
+            let reserve = |x: &Player| !x.is_selected && x.injury_weeks == 0;
+            let fit = match pos {
+                Position::Sub => self.players[out].position[0],
+                _ => pos,
+            };
+            let cover = match pos {
+                Position::Sub => self.best_fit(fit, reserve),
+                _ => self
+                    .best_fit(fit, bench)
+                    .or_else(|| self.best_fit(fit, reserve)),
+            };
+            match (cover, pos) {
+                (Some(i), _) => {
+                    // A replacement moving up leaves a place on the bench to fill
+                    if self.players[i].is_selected {
+                        if let Some(j) = self.best_fit(fit, reserve) {
+                            self.players[j].is_selected = true;
+                            self.players[j].selected_position = Position::Sub;
+                        }
+                    }
+                    self.players[i].is_selected = true;
+                    self.players[i].selected_position = pos;
+                }
+                (None, Position::Sub) => {}
+                (None, _) => return false,
+            }
+            self.players[out].is_selected = false;
+            self.players[out].selected_position = Position::Sub;
+            true
+        }
+
+        // Player passing the check who plays the position, or anyone passing it
+        fn best_fit(&self, pos: Position, check: impl Fn(&Player) -> bool) -> Option<usize> {
+            (0..self.players.len())
+                .find(|&i| check(&self.players[i]) && self.players[i].position.contains(&pos))
+                .or_else(|| (0..self.players.len()).find(|&i| check(&self.players[i])))
+        }
+
+        // Squad indices of the players on the pitch
+        pub fn on_field(&self) -> Vec<usize> {
+            (0..self.players.len())
+                .filter(|&i| {
+                    self.players[i].is_selected
+                        && self.players[i].selected_position != Position::Sub
+                })
+                .collect()
+        }
+
+        // Count a game for the starters and anyone on the pitch at the end
+        pub fn add_game(&mut self, starters: &[usize]) {
+            let finishers = self.on_field();
+            for (i, player) in self.players.iter_mut().enumerate() {
+                if starters.contains(&i) || finishers.contains(&i) {
+                    player.games += 1;
+                }
+            }
+        }
+
+        // Keep the match ratings of everyone who played
+        pub fn add_ratings(&mut self, ratings: &[PlayerRating]) {
+            for rating in ratings.iter() {
+                if let Some(r) = rating.rating {
+                    self.players[rating.index].add_rating(r);
+                }
+            }
+        }
+
+        // Selected position and selection of each player
+        pub fn lineup(&self) -> Lineup {
+            self.players
+                .iter()
+                .map(|x| (x.selected_position, x.is_selected))
+                .collect()
+        }
+
+        // Name the same lineup again, undoing any substitutions
+        pub fn restore_lineup(&mut self, lineup: &[(Position, bool)]) {
+            for (player, (pos, selected)) in self.players.iter_mut().zip(lineup) {
+                player.selected_position = *pos;
+                player.is_selected = *selected;
+            }
+        }
+
         // Team Challange Roll
         // Group challange roll for the entire team
         pub fn challange_roll(&self, attr: &AttributeTypes) -> i32 {
             self.players
                 .iter() // For each player
                 .filter(|x| x.is_selected) // filter by active players
-                .map(|x| x.challange_roll(attr).0) // Roll an individual challange roll
-                .sum() // Sum the team challange roll
+                .map(|x| x.support_roll(attr).0) // Roll an individual challange roll
+                .sum::<i32>() // Sum the team challange roll
+                + self.roll_mod()
         }
 
         // Forwards Challanage
@@ -61,8 +474,9 @@ pub mod team {
             self.players
                 .iter() // For each player
                 .filter(|x| x.is_selected && FORWARDS.contains(&x.position[0])) // filter by active players and forwards
-                .map(|x| x.challange_roll(attr).0) // Roll an individual challange roll
-                .sum() // Sum the team challange roll
+                .map(|x| x.support_roll(attr).0) // Roll an individual challange roll
+                .sum::<i32>() // Sum the team challange roll
+                + self.roll_mod()
         }
 
         // Backs Challanage
@@ -70,8 +484,57 @@ pub mod team {
             self.players
                 .iter() // For each player
                 .filter(|x| x.is_selected && !FORWARDS.contains(&x.position[0])) // filter by active players and backs
-                .map(|x| x.challange_roll(attr).0) // Roll an individual challange roll
-                .sum() // Sum the team challange roll
+                .map(|x| x.support_roll(attr).0) // Roll an individual challange roll
+                .sum::<i32>() // Sum the team challange roll
+                + self.roll_mod()
+        }
+    }
+
+    // Two different teams borrowed at once
+    pub fn pair_mut(teams: &mut [Team], a: usize, b: usize) -> (&mut Team, &mut Team) {
+        match a < b {
+            true => {
+                let (left, right) = teams.split_at_mut(b);
+                (&mut left[a], &mut right[0])
+            }
+            false => {
+                let (left, right) = teams.split_at_mut(a);
+                (&mut right[0], &mut left[b])
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn shirt(team: &Team, player: &Player) -> usize {
+            team.index_of(player) + 1
+        }
+
+        #[test]
+        fn active_player_is_the_selected_player() {
+            let team = Team::new();
+            assert_eq!(shirt(&team, team.active_player(Position::FlyHalf)), 10);
+            assert_eq!(shirt(&team, team.active_player(Position::Hooker)), 2);
+        }
+
+        #[test]
+        fn binned_fly_half_hands_the_kicking_on() {
+            let mut team = Team::new();
+            team.sin_bin(9, 600);
+            assert_eq!(shirt(&team, team.active_player(Position::FlyHalf)), 12);
+            team.sin_bin(11, 600);
+            assert_eq!(shirt(&team, team.active_player(Position::FlyHalf)), 15);
+        }
+
+        #[test]
+        fn binned_forward_is_covered_by_a_forward() {
+            let mut team = Team::new();
+            team.sin_bin(7, 600);
+            let cover = team.active_player(Position::Number8);
+            assert!(!cover.in_sin_bin());
+            assert!(FORWARDS.contains(&cover.selected_position));
         }
     }
 }
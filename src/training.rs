@@ -0,0 +1,168 @@
+// Module to develop players on the training ground
+
+pub mod training {
+    use crate::{
+        player::player::Player,
+        random_engine::rng_eng::{rand_chance, rand_index, AttributeTypes, ATTRIBUTE_TYPES},
+        team::team::Team,
+    };
+
+    // Progress from a single session on an attribute at zero
+    const SESSION_GAIN: f32 = 0.08;
+    // Each point already gained makes the next one harder
+    const DIMINISHING_RETURNS: f32 = 0.85;
+    // Extra progress from a player's own work on their focus
+    const INDIVIDUAL_GAIN: f32 = 0.05;
+    // Weeks of sustained individual focus to master an attribute
+    const ADVANTAGE_FOCUS_WEEKS: u32 = 16;
+    // Recovery sessions cut the risk of the rest of the week
+    const RECOVERY_RISK_MOD: f32 = 0.6;
+    const MAX_INJURY_WEEKS: usize = 6;
+
+    // Type of training session
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Focus {
+        StrengthConditioning,
+        Skills,
+        SetPiece,
+        Tactical,
+        Recovery, // Injured players heal faster
+    }
+
+    impl Focus {
+        // Attributes worked on in the session
+        pub fn attributes(&self) -> &'static [AttributeTypes] {
+            match *self {
+                Focus::StrengthConditioning => {
+                    &[AttributeTypes::Strength, AttributeTypes::Constitution]
+                }
+                Focus::Skills => &[AttributeTypes::Dexterity],
+                Focus::SetPiece => &[AttributeTypes::Strength, AttributeTypes::Intelligence],
+                Focus::Tactical => &[AttributeTypes::Intelligence, AttributeTypes::Wisdom],
+                Focus::Recovery => &[],
+            }
+        }
+
+        // Chance of a player picking up an injury in the session
+        pub fn injury_risk(&self) -> f32 {
+            match *self {
+                Focus::StrengthConditioning => 0.006,
+                Focus::Skills => 0.002,
+                Focus::SetPiece => 0.004,
+                Focus::Tactical => 0.,
+                Focus::Recovery => 0.,
+            }
+        }
+    }
+
+    // Weekly training plan for a team
+    pub struct TrainingPlan {
+        pub sessions: Vec<Focus>,
+    }
+
+    impl TrainingPlan {
+        // A bit of everything
+        pub fn new() -> TrainingPlan {
+            TrainingPlan {
+                sessions: vec![
+                    Focus::StrengthConditioning,
+                    Focus::Skills,
+                    Focus::SetPiece,
+                    Focus::Tactical,
+                    Focus::Recovery,
+                ],
+            }
+        }
+    }
+
+    // Training progress of a single player
+    #[derive(Clone)]
+    pub struct TrainingProgress {
+        pub gains: [f32; 6], // Progress towards the next point, by attribute
+        pub focus: Option<AttributeTypes>, // Individual focus
+        pub focus_weeks: u32, // Weeks spent on the current focus
+    }
+
+    impl TrainingProgress {
+        pub fn new() -> TrainingProgress {
+            TrainingProgress {
+                gains: [0.; 6],
+                focus: None,
+                focus_weeks: 0,
+            }
+        }
+
+        // Change the individual focus, starting the count again
+        pub fn set_focus(&mut self, focus: Option<AttributeTypes>) {
+            if self.focus != focus {
+                self.focus = focus;
+                self.focus_weeks = 0;
+            }
+        }
+    }
+
+    // A week of training for the whole squad
+    // Returns the squad indices of anyone injured
+    pub fn train_week(team: &mut Team) -> Vec<usize> {
+        let sessions = team.training.sessions.clone();
+        let recovery = sessions.iter().filter(|&&x| x == Focus::Recovery).count();
+        let risk_mod = RECOVERY_RISK_MOD.powi(recovery as i32);
+
+        let mut injured = Vec::new();
+        for i in 0..team.players.len() {
+            let player = &mut team.players[i];
+            // Injured players only recover
+            if player.injury_weeks > 0 {
+                player.injury_weeks = player.injury_weeks.saturating_sub(1 + recovery as u32);
+                continue;
+            }
+
+            for session in sessions.iter() {
+                for &attr in session.attributes() {
+                    train(player, attr, SESSION_GAIN);
+                }
+                if rand_chance(session.injury_risk() * risk_mod * player.injury_risk()) {
+                    player.injury_weeks = 1 + rand_index(MAX_INJURY_WEEKS) as u32;
+                    injured.push(i);
+                    break;
+                }
+            }
+
+            // Sustained individual work makes an attribute a strength
+            if let Some(attr) = player.training.focus {
+                train(player, attr, INDIVIDUAL_GAIN);
+                player.training.focus_weeks += 1;
+                if player.training.focus_weeks >= ADVANTAGE_FOCUS_WEEKS
+                    && !player.has_advantage.contains(&attr)
+                {
+                    player.has_advantage.push(attr);
+                }
+            }
+        }
+
+        // With nobody fit to cover, an injured starter plays through it
+        injured.retain(|&i| match team.withdraw(i) {
+            true => true,
+            false => {
+                team.players[i].injury_weeks = 0;
+                false
+            }
+        });
+        injured
+    }
+
+    // Add progress to an attribute, raising it a point once enough is built up
+    // Progress slows as the attribute rises and stops at the player's potential
+    fn train(player: &mut Player, attr: AttributeTypes, gain: f32) {
+        let score = player.attributes.get(attr);
+        if score >= player.potential {
+            return;
+        }
+        let i = ATTRIBUTE_TYPES.iter().position(|&x| x == attr).unwrap();
+        player.training.gains[i] += gain * DIMINISHING_RETURNS.powi(score.into());
+        if player.training.gains[i] >= 1. {
+            player.training.gains[i] -= 1.;
+            player.attributes.add(attr, 1);
+        }
+    }
+}
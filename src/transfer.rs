@@ -0,0 +1,390 @@
+// Module to handle contracts and moving players between clubs
+
+pub mod transfer {
+    use crate::{
+        career::career::{age_curve, rookie},
+        finance::finance::affordable,
+        player::player::{Player, Position, FRONT_ROW},
+        random_engine::{
+            rnd_name::NameGenerator,
+            rng_eng::{rand_index, ATTRIBUTE_TYPES},
+        },
+        team::team::Team,
+    };
+
+    // Money is counted in thousands
+    // Value of an average player at their peak
+    const BASE_VALUE: f32 = 200.;
+    // Value rises by this factor for every point of average attribute
+    const QUALITY_GROWTH: f32 = 1.25;
+    // Value lost for every year past the decline age, and gained before the peak from potential
+    const AGE_DECLINE: f32 = 0.15;
+    const POTENTIAL_VALUE: f32 = 0.05;
+    // Premiums for covering more positions and for scarce front row forwards
+    const VERSATILITY_PREMIUM: f32 = 0.1;
+    const FRONT_ROW_PREMIUM: f32 = 0.2;
+    // Value change for every rating point above or below average
+    const FORM_VALUE: f32 = 0.1;
+    const AVERAGE_RATING: f32 = 6.;
+    // Annual salary as a share of value
+    const SALARY_SHARE: f32 = 0.25;
+    const MIN_SALARY: u32 = 20;
+    // Release clauses are set above value
+    const RELEASE_CLAUSE_MARKUP: f32 = 2.;
+    const MAX_CONTRACT_YEARS: usize = 4;
+
+    // Ambitious managers pay over the odds
+    const AMBITIOUS_OVERBID: f32 = 1.2;
+    // Prudent managers keep some of the budget back
+    const PRUDENT_RESERVE: f32 = 0.25;
+    // Selling clubs hold out for more than value
+    const ASKING_MARKUP: f32 = 1.1;
+    // Players worth less than this are let go when their contract is up
+    const PRUDENT_RENEW_VALUE: u32 = 150;
+    const AMBITIOUS_RENEW_VALUE: u32 = 200;
+    // Only the most valuable free agents stay on the market
+    const MAX_FREE_AGENTS: usize = 100;
+
+    // Player contract
+    #[derive(Clone)]
+    pub struct Contract {
+        pub salary: u32, // Per season
+        pub years: u32,  // Seasons left to run
+        pub release_clause: Option<u32>,
+    }
+
+    impl Contract {
+        // Entry level contract
+        pub fn new() -> Contract {
+            Contract {
+                salary: MIN_SALARY,
+                years: 1 + rand_index(MAX_CONTRACT_YEARS) as u32,
+                release_clause: None,
+            }
+        }
+
+        // Contract on terms that match the player's value
+        pub fn offer(value: u32, years: u32) -> Contract {
+            Contract {
+                salary: ((value as f32 * SALARY_SHARE) as u32).max(MIN_SALARY),
+                years,
+                release_clause: Some((value as f32 * RELEASE_CLAUSE_MARKUP) as u32),
+            }
+        }
+    }
+
+    // Average attribute score
+    pub fn quality(player: &Player) -> f32 {
+        ATTRIBUTE_TYPES
+            .iter()
+            .map(|&x| player.attributes.get(x) as f32)
+            .sum::<f32>()
+            / ATTRIBUTE_TYPES.len() as f32
+    }
+
+    // Market value from attributes, age, positions and recent ratings
+    pub fn value(player: &Player) -> u32 {
+        let quality = quality(player);
+        let pos = player.position.first().copied().unwrap_or(Position::Sub);
+        let curve = age_curve(pos);
+
+        // Young players are valued on what they could become
+        let age = match player.age {
+            age if age < curve.peak => {
+                1. + (player.potential as f32 - quality).max(0.) * POTENTIAL_VALUE
+            }
+            age if age > curve.decline => {
+                (1. - (age - curve.decline) as f32 * AGE_DECLINE).max(AGE_DECLINE)
+            }
+            _ => 1.,
+        };
+        let positions = 1.
+            + player.position.len().saturating_sub(1) as f32 * VERSATILITY_PREMIUM
+            + match FRONT_ROW.contains(&pos) {
+                true => FRONT_ROW_PREMIUM,
+                false => 0.,
+            };
+        let form = match player.form() {
+            Some(rating) => (1. + (rating - AVERAGE_RATING) * FORM_VALUE).max(FORM_VALUE),
+            None => 1.,
+        };
+
+        (BASE_VALUE * QUALITY_GROWTH.powf(quality) * age * positions * form) as u32
+    }
+
+    // Transfer decisions for a club
+    pub trait TransferPolicy {
+        // Most the club will pay for a player, None to stay out of it
+        fn bid(&self, team: &Team, player: &Player) -> Option<u32>;
+
+        // Whether to accept a bid for one of our players
+        fn accept(&self, team: &Team, player: &Player, bid: u32) -> bool;
+
+        // Whether to offer a new contract to a player whose contract is up
+        fn renew(&self, team: &Team, player: &Player) -> bool;
+    }
+
+    // Salary a player would be paid on a new contract
+    pub fn asking_salary(player: &Player) -> u32 {
+        Contract::offer(value(player), 1).salary
+    }
+
+    // Prudent
+    // Pays value, keeps money in reserve and keeps hold of good players
+    // Never takes the wage bill past what the club can afford
+    pub struct Prudent;
+
+    impl TransferPolicy for Prudent {
+        fn bid(&self, team: &Team, player: &Player) -> Option<u32> {
+            let value = value(player);
+            let spend = (team.budget as f32 * (1. - PRUDENT_RESERVE)) as i64;
+            let wages = affordable(team, asking_salary(player), MIN_SALARY);
+            (value as i64 <= spend && wages).then_some(value)
+        }
+
+        fn accept(&self, _team: &Team, player: &Player, bid: u32) -> bool {
+            bid as f32 >= value(player) as f32 * ASKING_MARKUP
+        }
+
+        fn renew(&self, team: &Team, player: &Player) -> bool {
+            value(player) >= PRUDENT_RENEW_VALUE
+                && affordable(team, asking_salary(player), player.contract.salary)
+        }
+    }
+
+    // Ambitious
+    // Overpays for the best, sells only for big money
+    pub struct Ambitious;
+
+    impl TransferPolicy for Ambitious {
+        fn bid(&self, team: &Team, player: &Player) -> Option<u32> {
+            let bid = (value(player) as f32 * AMBITIOUS_OVERBID) as u32;
+            (bid as i64 <= team.budget).then_some(bid)
+        }
+
+        fn accept(&self, _team: &Team, player: &Player, bid: u32) -> bool {
+            bid as f32 >= value(player) as f32 * ASKING_MARKUP * AMBITIOUS_OVERBID
+        }
+
+        fn renew(&self, _team: &Team, player: &Player) -> bool {
+            value(player) >= AMBITIOUS_RENEW_VALUE
+        }
+    }
+
+    // Player moving club, None for free agency
+    pub struct Transfer {
+        pub name: String,
+        pub from: Option<usize>,
+        pub to: Option<usize>,
+        pub fee: u32,
+    }
+
+    // Transfer window
+    // Runs between seasons over every club in a league and the free agents
+    pub struct TransferMarket {
+        pub free_agents: Vec<Player>,
+        pub transfers: Vec<Transfer>,
+    }
+
+    impl TransferMarket {
+        pub fn new() -> TransferMarket {
+            TransferMarket {
+                free_agents: Vec::new(),
+                transfers: Vec::new(),
+            }
+        }
+
+        // Run the window, returns the moves made
+        // Rookies brought in to fill gaps get names from the generator
+        pub fn window(&mut self, teams: &mut [Team], names: &mut NameGenerator) -> Vec<Transfer> {
+            self.transfers.clear();
+            self.expire_contracts(teams, names);
+            self.bids(teams, names);
+
+            self.free_agents
+                .sort_by_key(|x| std::cmp::Reverse(value(x)));
+            // Free agents nobody wants leave the game and free up their names
+            for player in self.free_agents.iter().skip(MAX_FREE_AGENTS) {
+                names.release(&player.name);
+            }
+            self.free_agents.truncate(MAX_FREE_AGENTS);
+            std::mem::take(&mut self.transfers)
+        }
+
+        // Contracts run down a year, clubs renew or release those that are up
+        fn expire_contracts(&mut self, teams: &mut [Team], names: &mut NameGenerator) {
+            for (i, team) in teams.iter_mut().enumerate() {
+                for slot in 0..team.players.len() {
+                    let player = &mut team.players[slot];
+                    player.contract.years = player.contract.years.saturating_sub(1);
+                    if player.contract.years > 0 {
+                        continue;
+                    }
+
+                    let player = &team.players[slot];
+                    if team.manager.renew(team, player) {
+                        let value = value(player);
+                        let years = 1 + rand_index(MAX_CONTRACT_YEARS) as u32;
+                        team.players[slot].contract = Contract::offer(value, years);
+                    } else {
+                        // Free agents have to be worth a bid, wages included
+                        let replacement = self
+                            .best_free_agent(&player.position, None)
+                            .filter(|&j| team.manager.bid(team, &self.free_agents[j]).is_some());
+                        let replacement = match replacement {
+                            Some(j) => self.sign_free_agent(j, i),
+                            None => rookie(player, names),
+                        };
+                        let released = team.replace(slot, replacement);
+                        self.transfers.push(Transfer {
+                            name: released.name.clone(),
+                            from: Some(i),
+                            to: None,
+                            fee: 0,
+                        });
+                        self.free_agents.push(released);
+                    }
+                }
+            }
+        }
+
+        // Each club goes after an upgrade on its weakest starter
+        // Clubs with the most money move first
+        fn bids(&mut self, teams: &mut [Team], names: &mut NameGenerator) {
+            let mut order = (0..teams.len()).collect::<Vec<usize>>();
+            order.sort_by_key(|&i| std::cmp::Reverse(teams[i].budget));
+
+            for buyer in order {
+                let Some(slot) = weakest_starter(&teams[buyer]) else {
+                    continue;
+                };
+                let outgoing = &teams[buyer].players[slot];
+                let positions = outgoing.position.clone();
+                let current = quality(outgoing);
+
+                // Free agents cost nothing up front
+                if let Some(j) = self.best_free_agent(&positions, Some(current)) {
+                    if teams[buyer]
+                        .manager
+                        .bid(&teams[buyer], &self.free_agents[j])
+                        .is_some()
+                    {
+                        let player = self.sign_free_agent(j, buyer);
+                        self.release(teams, buyer, slot, player);
+                        continue;
+                    }
+                }
+
+                // Best affordable player at another club who would improve the side
+                let mut targets = Vec::new();
+                for (seller, team) in teams.iter().enumerate() {
+                    if seller == buyer {
+                        continue;
+                    }
+                    for (k, player) in team.players.iter().enumerate() {
+                        if !positions.contains(&player.position[0]) || quality(player) <= current {
+                            continue;
+                        }
+                        if let Some(bid) = teams[buyer].manager.bid(&teams[buyer], player) {
+                            // A release clause has to be accepted
+                            let met = player.contract.release_clause.is_some_and(|x| bid >= x);
+                            if met || team.manager.accept(team, player, bid) {
+                                targets.push((seller, k, bid));
+                            }
+                        }
+                    }
+                }
+                let best = targets.into_iter().max_by(|a, b| {
+                    quality(&teams[a.0].players[a.1]).total_cmp(&quality(&teams[b.0].players[b.1]))
+                });
+                if let Some((seller, k, fee)) = best {
+                    self.buy(teams, names, (buyer, slot), (seller, k), fee);
+                }
+            }
+        }
+
+        // Move a player between clubs for a fee
+        // The selling club fills the gap from the free agents, or with a rookie
+        fn buy(
+            &mut self,
+            teams: &mut [Team],
+            names: &mut NameGenerator,
+            (buyer, slot): (usize, usize),
+            (seller, k): (usize, usize),
+            fee: u32,
+        ) {
+            let positions = teams[seller].players[k].position.clone();
+            let replacement = match self.best_free_agent(&positions, None) {
+                Some(j) => self.sign_free_agent(j, seller),
+                None => rookie(&teams[seller].players[k], names),
+            };
+            let mut player = teams[seller].replace(k, replacement);
+            teams[seller].budget += fee as i64;
+            teams[buyer].budget -= fee as i64;
+            self.transfers.push(Transfer {
+                name: player.name.clone(),
+                from: Some(seller),
+                to: Some(buyer),
+                fee,
+            });
+
+            let years = 1 + rand_index(MAX_CONTRACT_YEARS) as u32;
+            player.contract = Contract::offer(value(&player), years);
+            self.release(teams, buyer, slot, player);
+        }
+
+        // Put a new signing in the squad and release the player they replace
+        fn release(&mut self, teams: &mut [Team], team: usize, slot: usize, player: Player) {
+            let released = teams[team].replace(slot, player);
+            self.transfers.push(Transfer {
+                name: released.name.clone(),
+                from: Some(team),
+                to: None,
+                fee: 0,
+            });
+            self.free_agents.push(released);
+        }
+
+        // Best free agent for the positions, better than the given quality
+        fn best_free_agent(
+            &self,
+            positions: &[Position],
+            better_than: Option<f32>,
+        ) -> Option<usize> {
+            (0..self.free_agents.len())
+                .filter(|&j| {
+                    let player = &self.free_agents[j];
+                    player
+                        .position
+                        .first()
+                        .is_some_and(|x| positions.contains(x))
+                        && better_than.is_none_or(|x| quality(player) > x)
+                })
+                .max_by(|&a, &b| {
+                    quality(&self.free_agents[a]).total_cmp(&quality(&self.free_agents[b]))
+                })
+        }
+
+        // Take a player out of free agency on a new contract
+        fn sign_free_agent(&mut self, j: usize, team: usize) -> Player {
+            let mut player = self.free_agents.remove(j);
+            let years = 1 + rand_index(MAX_CONTRACT_YEARS) as u32;
+            player.contract = Contract::offer(value(&player), years);
+            self.transfers.push(Transfer {
+                name: player.name.clone(),
+                from: None,
+                to: Some(team),
+                fee: 0,
+            });
+            player
+        }
+    }
+
+    // Player on the pitch with the lowest quality
+    fn weakest_starter(team: &Team) -> Option<usize> {
+        team.on_field()
+            .into_iter()
+            .filter(|&i| !team.players[i].position.is_empty())
+            .min_by(|&a, &b| quality(&team.players[a]).total_cmp(&quality(&team.players[b])))
+    }
+}